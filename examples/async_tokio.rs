@@ -0,0 +1,10 @@
+use input_lib::async_input;
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let name: String = async_input!("Enter your name: ").await?;
+    let age: u8 = async_input!("Hi {}, how old are you? ", name).await?;
+    println!("{} is {} years old.", name, age);
+
+    Ok(())
+}
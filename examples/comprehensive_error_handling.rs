@@ -17,6 +17,30 @@ fn main() {
                 println!("An I/O error occurred.");
                 return;
             },
+            InputError::Empty => {
+                println!("No input was given.");
+                return;
+            },
+            InputError::Validation(msg) => {
+                println!("Invalid input: {}", msg);
+                return;
+            },
+            InputError::Timeout(timeout) => {
+                println!("No input within {:?}.", timeout);
+                return;
+            },
+            InputError::TokenCount { expected, found } => {
+                println!("Expected {} values, got {}.", expected, found);
+                return;
+            },
+            InputError::OutOfRange { value, bounds } => {
+                println!("{} is outside {}.", value, bounds);
+                return;
+            },
+            InputError::Mismatch => {
+                println!("The entries did not match.");
+                return;
+            },
         },
     };
 
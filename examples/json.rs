@@ -0,0 +1,19 @@
+use input_lib::{read_json_from, PrintStyle};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+struct Point {
+    x: f64,
+    y: f64,
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let point: Point = read_json_from(
+        &mut std::io::stdin().lock(),
+        Some(format_args!("Enter a point as JSON (e.g. {{\"x\": 1, \"y\": 2}}): ")),
+        PrintStyle::Continue,
+    )?;
+    println!("Distance from origin: {}", point.x.hypot(point.y));
+
+    Ok(())
+}
@@ -0,0 +1,10 @@
+use input_lib::{input, password};
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let user: String = input!("Username: ")?;
+    let secret = password!("Password for {}: ", user)?;
+
+    println!("Read a {}-character password for {}.", secret.chars().count(), user);
+
+    Ok(())
+}
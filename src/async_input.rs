@@ -0,0 +1,200 @@
+//! Non-blocking counterparts of the line readers, built on tokio.
+
+use std::{fmt::Arguments, str::FromStr, sync::OnceLock};
+
+use tokio::{
+    io::{AsyncBufRead, AsyncBufReadExt, AsyncWrite, AsyncWriteExt, BufReader, Stdin},
+    sync::Mutex,
+};
+
+use crate::{InputError, PrintStyle, TrimStyle};
+
+/// A macro that:
+/// - optionally prints a prompt (with `tokio::io::stdout`),
+/// - reads **one line** from stdin without blocking the executor,
+/// - trims leading and trailing whitespace (see [`TrimStyle::Trim`]),
+/// - returns `Err(InputError::Eof)` if EOF is encountered,
+/// - returns `Err(InputError::Parse(e))` if the input cannot be parsed.
+///
+/// The macro evaluates to a future; `.await` it. See [`read_stdin_async`].
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::async_input;
+///
+/// # async fn run() -> Result<(), Box<dyn std::error::Error>> {
+/// let name: String = async_input!("Enter your name: ").await?;
+/// # Ok(())
+/// # }
+/// ```
+#[macro_export]
+macro_rules! async_input {
+    () => {{
+        $crate::read_stdin_async(None, $crate::PrintStyle::Continue, $crate::TrimStyle::Trim)
+    }};
+    ($($arg:tt)*) => {{
+        $crate::read_stdin_async(
+            Some(format_args!($($arg)*)),
+            $crate::PrintStyle::Continue,
+            $crate::TrimStyle::Trim,
+        )
+    }};
+}
+
+/// The async version of [`read_input_from`](crate::read_input_from):
+/// 1. Optionally prints a prompt with `tokio::io::stdout` (and flushes, unless
+///    `print_style` is `PrintStyle::NoFlush`).
+/// 2. Reads one line from the provided `AsyncBufRead`.
+/// 3. Returns `Err(InputError::Eof)` if EOF is reached.
+/// 4. Parses into type `T`, returning `Err(InputError::Parse)` on failure.
+/// 5. Returns `Err(InputError::Io)` on I/O failure.
+///
+/// The prompt is formatted before the future is created, so the future
+/// doesn't borrow it and is `Send` whenever the reader is.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_input_from_async, PrintStyle};
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let mut reader = Cursor::new("42\n");
+///
+/// let number: i32 = read_input_from_async(
+///     &mut reader,
+///     Some(format_args!("Enter a number: ")),
+///     PrintStyle::Continue,
+/// ).await.unwrap();
+///
+/// assert_eq!(number, 42);
+/// # });
+/// ```
+pub fn read_input_from_async<'a, R, T>(
+    reader: &'a mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+) -> impl Future<Output = Result<T, InputError<T::Err>>> + use<'a, R, T>
+where
+    R: AsyncBufRead + Unpin,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    let prompt = format_prompt(prompt, print_style);
+
+    async move {
+        if matches!(print_style, PrintStyle::Stderr | PrintStyle::StderrNewLine) {
+            read_line_async(reader, &mut tokio::io::stderr(), prompt, print_style, TrimStyle::Trim)
+                .await
+        } else {
+            read_line_async(reader, &mut tokio::io::stdout(), prompt, print_style, TrimStyle::Trim)
+                .await
+        }
+    }
+}
+
+/// Like [`read_input_from_async`], but prints the prompt to `writer` instead of stdout.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_input_from_async_with_writer, PrintStyle};
+///
+/// # tokio::runtime::Builder::new_current_thread().build().unwrap().block_on(async {
+/// let mut reader = Cursor::new("Alice\n");
+/// let mut output = Vec::new();
+///
+/// let name: String = read_input_from_async_with_writer(
+///     &mut reader,
+///     &mut output,
+///     Some(format_args!("Name: ")),
+///     PrintStyle::Continue,
+/// ).await.unwrap();
+///
+/// assert_eq!(name, "Alice");
+/// assert_eq!(output, b"Name: ");
+/// # });
+/// ```
+pub fn read_input_from_async_with_writer<'a, R, W, T>(
+    reader: &'a mut R,
+    writer: &'a mut W,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+) -> impl Future<Output = Result<T, InputError<T::Err>>> + use<'a, R, W, T>
+where
+    R: AsyncBufRead + Unpin,
+    W: AsyncWrite + Unpin,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    let prompt = format_prompt(prompt, print_style);
+    read_line_async(reader, writer, prompt, print_style, TrimStyle::Trim)
+}
+
+/// Like [`read_input_from_async`], but reads from the process's stdin and lets
+/// the caller pick the `trim_style`. This is what [`async_input!`] calls.
+///
+/// Stdin is wrapped in a single buffered reader shared by every call, so input
+/// buffered by one read isn't lost to the next.
+pub fn read_stdin_async<T>(
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    trim_style: TrimStyle,
+) -> impl Future<Output = Result<T, InputError<T::Err>>> + use<T>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    static STDIN: OnceLock<Mutex<BufReader<Stdin>>> = OnceLock::new();
+
+    let prompt = format_prompt(prompt, print_style);
+
+    async move {
+        let mut reader = STDIN.get_or_init(|| Mutex::new(BufReader::new(tokio::io::stdin()))).lock().await;
+        if matches!(print_style, PrintStyle::Stderr | PrintStyle::StderrNewLine) {
+            read_line_async(&mut *reader, &mut tokio::io::stderr(), prompt, print_style, trim_style).await
+        } else {
+            read_line_async(&mut *reader, &mut tokio::io::stdout(), prompt, print_style, trim_style).await
+        }
+    }
+}
+
+/// Formats the prompt up front, adding the newline for the `*NewLine` styles.
+/// Returns `None` for `PrintStyle::Silent`, so nothing is written.
+fn format_prompt(prompt: Option<Arguments<'_>>, print_style: PrintStyle) -> Option<String> {
+    if print_style == PrintStyle::Silent {
+        return None;
+    }
+    prompt.map(|prompt_args| match print_style {
+        PrintStyle::NewLine | PrintStyle::StderrNewLine => format!("{}\n", prompt_args),
+        _ => prompt_args.to_string(),
+    })
+}
+
+/// Writes the already formatted prompt to `writer`, then reads and parses one line.
+async fn read_line_async<R, W, T>(
+    reader: &mut R,
+    writer: &mut W,
+    prompt: Option<String>,
+    print_style: PrintStyle,
+    trim_style: TrimStyle,
+) -> Result<T, InputError<T::Err>>
+where
+    R: AsyncBufRead + Unpin,
+    W: AsyncWrite + Unpin,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    if let Some(prompt) = prompt {
+        writer.write_all(prompt.as_bytes()).await?;
+        if print_style != PrintStyle::NoFlush {
+            writer.flush().await?;
+        }
+    }
+
+    let mut input = String::new();
+    if reader.read_line(&mut input).await? == 0 {
+        return Err(InputError::Eof);
+    }
+
+    trim_style.apply(&input).parse::<T>().map_err(InputError::Parse)
+}
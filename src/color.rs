@@ -0,0 +1,163 @@
+//! ANSI-colored prompts.
+//!
+//! Colors are only emitted when the prompt's stream (stdout, or stderr for the
+//! stderr print styles) is a terminal and the `NO_COLOR` environment variable
+//! is unset or empty (see <https://no-color.org>), so piped output stays plain text.
+
+use std::{
+    fmt,
+    io::{self, IsTerminal},
+};
+
+use crate::PrintStyle;
+
+/// A foreground color for a prompt.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_input_from, Color, PrintStyle, TrimStyle};
+///
+/// let mut reader = Cursor::new("Alice\n");
+/// let name: String = read_input_from(
+///     &mut reader,
+///     Some(format_args!("{}", Color::Cyan.paint("Name: "))),
+///     PrintStyle::Continue,
+///     TrimStyle::Trim,
+/// ).unwrap();
+///
+/// assert_eq!(name, "Alice");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    /// Black (ANSI code 30).
+    Black,
+    /// Red (ANSI code 31).
+    Red,
+    /// Green (ANSI code 32).
+    Green,
+    /// Yellow (ANSI code 33).
+    Yellow,
+    /// Blue (ANSI code 34).
+    Blue,
+    /// Magenta (ANSI code 35).
+    Magenta,
+    /// Cyan (ANSI code 36).
+    Cyan,
+    /// White (ANSI code 37); many terminals show it as light gray.
+    White,
+}
+
+impl Color {
+    /// Wraps `text` so it is displayed in this color on stdout.
+    ///
+    /// Whether escape codes are emitted is decided now, from stdout and
+    /// `NO_COLOR`; use [`Colored::force`] to override it.
+    pub fn paint<T: fmt::Display>(self, text: T) -> Colored<T> {
+        self.paint_for(text, PrintStyle::Continue)
+    }
+
+    /// Like [`Color::paint`], but checks the stream `print_style` prints to,
+    /// so prompts sent to stderr are colored when stderr is a terminal.
+    ///
+    /// # Usage:
+    /// ```
+    /// use std::io::Cursor;
+    /// use input_lib::{read_input_from, Color, PrintStyle, TrimStyle};
+    ///
+    /// let mut reader = Cursor::new("Alice\n");
+    /// let style = PrintStyle::Stderr;
+    /// let name: String = read_input_from(
+    ///     &mut reader,
+    ///     Some(format_args!("{}", Color::Cyan.paint_for("Name: ", style))),
+    ///     style,
+    ///     TrimStyle::Trim,
+    /// ).unwrap();
+    ///
+    /// assert_eq!(name, "Alice");
+    /// ```
+    pub fn paint_for<T: fmt::Display>(self, text: T, print_style: PrintStyle) -> Colored<T> {
+        Colored { text, color: self, enabled: color_enabled(print_style) }
+    }
+
+    /// The ANSI SGR code for this foreground color.
+    fn code(self) -> u8 {
+        match self {
+            Color::Black => 30,
+            Color::Red => 31,
+            Color::Green => 32,
+            Color::Yellow => 33,
+            Color::Blue => 34,
+            Color::Magenta => 35,
+            Color::Cyan => 36,
+            Color::White => 37,
+        }
+    }
+}
+
+/// Text displayed in a [`Color`], created by [`Color::paint`].
+///
+/// # Usage:
+/// ```
+/// use input_lib::Color;
+///
+/// assert_eq!(Color::Green.paint("ok").force(true).to_string(), "\x1b[32mok\x1b[0m");
+/// assert_eq!(Color::Green.paint("ok").force(false).to_string(), "ok");
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Colored<T> {
+    text: T,
+    color: Color,
+    enabled: bool,
+}
+
+impl<T> Colored<T> {
+    /// Emits the escape codes if `enabled` is `true`, regardless of the terminal.
+    pub fn force(mut self, enabled: bool) -> Self {
+        self.enabled = enabled;
+        self
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Colored<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.enabled {
+            write!(f, "\x1b[{}m{}\x1b[0m", self.color.code(), self.text)
+        } else {
+            write!(f, "{}", self.text)
+        }
+    }
+}
+
+/// Returns `true` if the stream `print_style` prints to is a terminal and
+/// `NO_COLOR` is unset or empty.
+fn color_enabled(print_style: PrintStyle) -> bool {
+    let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+    let is_terminal = match print_style {
+        PrintStyle::Stderr | PrintStyle::StderrNewLine => io::stderr().is_terminal(),
+        _ => io::stdout().is_terminal(),
+    };
+    !no_color && is_terminal
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paint_for_checks_the_prompts_stream() {
+        let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+        let colored = |text: String| text.starts_with('\x1b');
+
+        assert_eq!(colored(Color::Red.paint_for("x", PrintStyle::Stderr).to_string()), !no_color && io::stderr().is_terminal());
+        assert_eq!(colored(Color::Red.paint_for("x", PrintStyle::NewLine).to_string()), !no_color && io::stdout().is_terminal());
+    }
+
+    #[test]
+    fn force_overrides_the_terminal_check() {
+        for (color, code) in [(Color::Black, 30), (Color::Cyan, 36), (Color::White, 37)] {
+            assert_eq!(color.paint("x").force(true).to_string(), format!("\x1b[{code}mx\x1b[0m"));
+            assert_eq!(color.paint("x").force(false).to_string(), "x");
+        }
+    }
+}
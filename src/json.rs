@@ -0,0 +1,51 @@
+//! Reading JSON values, one per line, with serde.
+
+use std::{fmt::Arguments, io::BufRead};
+
+use serde::de::DeserializeOwned;
+
+use crate::{InputError, PrintStyle, TrimStyle, print_prompt, read_line};
+
+/// Like [`read_input_from`](crate::read_input_from), but deserializes the line as JSON:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line from the provided `BufRead`.
+/// 3. Returns `Err(InputError::Eof)` if EOF is reached.
+/// 4. Deserializes it with `serde_json::from_str`, returning `Err(InputError::Parse)` on failure.
+/// 5. Returns `Err(InputError::Io)` on I/O failure.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_json_from, InputError, PrintStyle};
+/// use serde::Deserialize;
+///
+/// #[derive(Debug, PartialEq, Deserialize)]
+/// struct User {
+///     name: String,
+///     age: u8,
+/// }
+///
+/// let mut reader = Cursor::new("{\"name\": \"Alice\", \"age\": 30}\n{\"name\": \"Bob\"\n");
+///
+/// let user: User = read_json_from(&mut reader, None, PrintStyle::Continue).unwrap();
+/// assert_eq!(user, User { name: "Alice".to_string(), age: 30 });
+///
+/// let malformed = read_json_from::<_, User>(&mut reader, None, PrintStyle::Continue);
+/// assert!(matches!(malformed, Err(InputError::Parse(e)) if e.is_eof()));
+/// ```
+pub fn read_json_from<R, T>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+) -> Result<T, InputError<serde_json::Error>>
+where
+    R: BufRead,
+    T: DeserializeOwned,
+{
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let input = read_line(reader, TrimStyle::TrailingNewline)?.ok_or(InputError::Eof)?;
+    serde_json::from_str(&input).map_err(InputError::Parse)
+}
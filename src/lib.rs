@@ -1,14 +1,64 @@
-use std::{fmt::Arguments, io::{self, BufRead, Write}, str::FromStr};
+use std::{
+    collections::HashMap,
+    convert::Infallible,
+    fmt::Arguments,
+    io::{self, BufRead, IsTerminal, Read, Write},
+    marker::PhantomData,
+    num::ParseIntError,
+    ops::{Bound, RangeBounds},
+    str::FromStr,
+    sync::mpsc::{self, RecvTimeoutError},
+    thread,
+    time::Duration,
+};
+
+pub mod testing;
+
+#[cfg(feature = "password")]
+mod password;
+#[cfg(feature = "password")]
+pub use password::{
+    read_masked_from, read_password, read_password_confirmed, read_password_confirmed_from, read_password_from,
+    read_password_masked, read_secret_from,
+};
+
+#[cfg(feature = "async-tokio")]
+mod async_input;
+#[cfg(feature = "async-tokio")]
+pub use async_input::{read_input_from_async, read_input_from_async_with_writer, read_stdin_async};
+
+#[cfg(feature = "color")]
+mod color;
+#[cfg(feature = "color")]
+pub use color::{Color, Colored};
+
+#[cfg(feature = "serde")]
+mod json;
+#[cfg(feature = "serde")]
+pub use json::read_json_from;
+
+#[cfg(feature = "regex")]
+mod matching;
+#[cfg(feature = "regex")]
+pub use matching::read_matching;
 
 /// A macro that:
 /// - optionally prints a prompt (with `print!`).
 /// - reads **one line** from stdin.
+/// - trims leading and trailing whitespace (see [`TrimStyle::Trim`]).
 /// - returns `Err(InputError::Eof)` if EOF is encountered.
 /// - returns `Err(InputError::Parse(e))` if the input cannot be parsed.
 /// - returns `Err(InputError::Io(e))` if an IO error occurs.
 ///
+/// The target type is usually inferred, but can also be given first, after
+/// `as` (`input!(as u32, "Count: ")`). The `as` keeps it apart from prompts
+/// built by other macros, like `input!(concat!(...))`. This works the same for
+/// [`inputln!`], [`inputerr!`] and [`inputlnerr!`].
+///
 /// # Usage:
 /// ```no_run
+/// use input_lib::input;
+///
 /// // No prompt
 /// let text: String = input!().unwrap();
 ///
@@ -18,6 +68,10 @@ use std::{fmt::Arguments, io::{self, BufRead, Write}, str::FromStr};
 /// // Formatted prompt
 /// let user = "Alice";
 /// let age: String = input!("Enter {}'s age: ", user).unwrap();
+///
+/// // Explicit target type, for expression position
+/// let count = input!(as u32, "Count: ").unwrap();
+/// let doubled = 2 * input!(as u32).unwrap();
 /// ```
 #[macro_export]
 macro_rules! input {
@@ -26,13 +80,31 @@ macro_rules! input {
             &mut ::std::io::stdin().lock(),
             None,
             $crate::PrintStyle::Continue,
+            $crate::TrimStyle::Trim,
+        )
+    }};
+    (as $ty:ty) => {{
+        $crate::read_input_from::<_, $ty>(
+            &mut ::std::io::stdin().lock(),
+            None,
+            $crate::PrintStyle::Continue,
+            $crate::TrimStyle::Trim,
+        )
+    }};
+    (as $ty:ty, $($arg:tt)+) => {{
+        $crate::read_input_from::<_, $ty>(
+            &mut ::std::io::stdin().lock(),
+            Some(format_args!($($arg)+)),
+            $crate::PrintStyle::Continue,
+            $crate::TrimStyle::Trim,
         )
     }};
     ($($arg:tt)*) => {{
         $crate::read_input_from(
             &mut ::std::io::stdin().lock(),
             Some(format_args!($($arg)*)),
-            $crate::PrintStyle::Continue
+            $crate::PrintStyle::Continue,
+            $crate::TrimStyle::Trim,
         )
     }};
 }
@@ -40,6 +112,7 @@ macro_rules! input {
 /// A macro that:
 /// - prints the prompt on its own line (with `println!`),
 /// - then reads one line,
+/// - trims leading and trailing whitespace (see [`TrimStyle::Trim`]).
 /// - returns `Err(InputError::Eof)` if EOF is encountered.
 /// - returns `Err(InputError::Parse(e))` if the input cannot be parsed.
 /// - returns `Err(InputError::Io(e))` if an IO error occurs.
@@ -47,7 +120,36 @@ macro_rules! input {
 ///
 /// # Usage:
 /// ```no_run
+/// use input_lib::inputln;
+///
 /// let line: String = inputln!("What's your favorite color?").unwrap();
+///
+/// // Formatted prompt
+/// let user = "Alice";
+/// let color: String = inputln!("What's {}'s favorite color?", user).unwrap();
+///
+/// // Explicit target type
+/// let year = inputln!(as u16, "Which year were you born in?").unwrap();
+/// ```
+///
+/// The prompt is written before the line is read, followed by a newline. The
+/// same read against a buffer instead of stdout shows exactly what is printed:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_input_from_with_writer, PrintStyle, TrimStyle};
+///
+/// let mut reader = Cursor::new("  blue \n");
+/// let mut output = Vec::new();
+/// let color: String = read_input_from_with_writer(
+///     &mut reader,
+///     &mut output,
+///     Some(format_args!("What's your favorite color?")),
+///     PrintStyle::NewLine,
+///     TrimStyle::Trim,
+/// ).unwrap();
+///
+/// assert_eq!(output, b"What's your favorite color?\n");
+/// assert_eq!(color, "blue");
 /// ```
 #[macro_export]
 macro_rules! inputln {
@@ -55,89 +157,5266 @@ macro_rules! inputln {
         $crate::read_input_from(
           &mut ::std::io::stdin().lock(), 
           None, 
-          $crate::PrintStyle::NewLine
+          $crate::PrintStyle::NewLine,
+          $crate::TrimStyle::Trim,
+        )
+    }};
+    (as $ty:ty) => {{
+        $crate::read_input_from::<_, $ty>(
+            &mut ::std::io::stdin().lock(),
+            None,
+            $crate::PrintStyle::NewLine,
+            $crate::TrimStyle::Trim,
+        )
+    }};
+    (as $ty:ty, $($arg:tt)+) => {{
+        $crate::read_input_from::<_, $ty>(
+            &mut ::std::io::stdin().lock(),
+            Some(format_args!($($arg)+)),
+            $crate::PrintStyle::NewLine,
+            $crate::TrimStyle::Trim,
         )
     }};
     ($($arg:tt)*) => {{
         $crate::read_input_from(
           &mut ::std::io::stdin().lock(), 
-          None, 
-          $crate::PrintStyle::NewLine
+          Some(format_args!($($arg)*)), 
+          $crate::PrintStyle::NewLine,
+          $crate::TrimStyle::Trim,
         )
     }};
 }
 
-/// A single function that:
-/// 1. Optionally prints a prompt (and flushes).
-/// 2. Reads one line from the provided `BufRead`.
-/// 3. Returns `Err(InputError::Eof)` if EOF is reached.
-/// 4. Parses into type `T`, returning `Err(InputError::Parse)` on failure.
-/// 5. Returns `Err(InputError::Io)` on I/O failure.
-pub fn read_input_from<R, T>(
-    reader: &mut R,
-    prompt: Option<Arguments<'_>>,
-    print_style: PrintStyle,
-) -> Result<T, InputError<T::Err>>
-where
-    R: BufRead,
-    T: FromStr,
-    T::Err: std::fmt::Display + std::fmt::Debug,
-{
-    if let Some(prompt_args) = prompt {
-        match print_style {
-            PrintStyle::Continue => {
-                // Use print! for no newline
-                print!("{}", prompt_args);
-            }
-            PrintStyle::NewLine => {
-                // Use println! for adding a newline
-                println!("{}", prompt_args);
-            }
-        }
-        // Always flush so the user sees the prompt immediately
-        io::stdout().flush().map_err(InputError::Io)?;
-    }
+/// A macro that behaves like [`input!`], but prints the prompt to stderr (with `eprint!`),
+/// so stdout can be piped cleanly.
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::inputerr;
+///
+/// let name: String = inputerr!("Enter your name: ").unwrap();
+/// println!("{}", name);
+/// ```
+#[macro_export]
+macro_rules! inputerr {
+    () => {{
+        $crate::read_input_from(
+            &mut ::std::io::stdin().lock(),
+            None,
+            $crate::PrintStyle::Stderr,
+            $crate::TrimStyle::Trim,
+        )
+    }};
+    (as $ty:ty) => {{
+        $crate::read_input_from::<_, $ty>(
+            &mut ::std::io::stdin().lock(),
+            None,
+            $crate::PrintStyle::Stderr,
+            $crate::TrimStyle::Trim,
+        )
+    }};
+    (as $ty:ty, $($arg:tt)+) => {{
+        $crate::read_input_from::<_, $ty>(
+            &mut ::std::io::stdin().lock(),
+            Some(format_args!($($arg)+)),
+            $crate::PrintStyle::Stderr,
+            $crate::TrimStyle::Trim,
+        )
+    }};
+    ($($arg:tt)*) => {{
+        $crate::read_input_from(
+            &mut ::std::io::stdin().lock(),
+            Some(format_args!($($arg)*)),
+            $crate::PrintStyle::Stderr,
+            $crate::TrimStyle::Trim,
+        )
+    }};
+}
 
-    let mut input = String::new();
-    let bytes_read = reader.read_line(&mut input).map_err(InputError::Io)?;
-    
-    // If 0, that's EOF — return Eof error
-    if bytes_read == 0 {
-        return Err(InputError::Eof);
-    }
+/// A macro that behaves like [`inputln!`], but prints the prompt on its own line
+/// to stderr (with `eprintln!`), so stdout can be piped cleanly.
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::inputlnerr;
+///
+/// let color: String = inputlnerr!("What's your favorite color?").unwrap();
+/// println!("{}", color);
+/// ```
+#[macro_export]
+macro_rules! inputlnerr {
+    () => {{
+        $crate::read_input_from(
+            &mut ::std::io::stdin().lock(),
+            None,
+            $crate::PrintStyle::StderrNewLine,
+            $crate::TrimStyle::Trim,
+        )
+    }};
+    (as $ty:ty) => {{
+        $crate::read_input_from::<_, $ty>(
+            &mut ::std::io::stdin().lock(),
+            None,
+            $crate::PrintStyle::StderrNewLine,
+            $crate::TrimStyle::Trim,
+        )
+    }};
+    (as $ty:ty, $($arg:tt)+) => {{
+        $crate::read_input_from::<_, $ty>(
+            &mut ::std::io::stdin().lock(),
+            Some(format_args!($($arg)+)),
+            $crate::PrintStyle::StderrNewLine,
+            $crate::TrimStyle::Trim,
+        )
+    }};
+    ($($arg:tt)*) => {{
+        $crate::read_input_from(
+            &mut ::std::io::stdin().lock(),
+            Some(format_args!($($arg)*)),
+            $crate::PrintStyle::StderrNewLine,
+            $crate::TrimStyle::Trim,
+        )
+    }};
+}
 
-    let trimmed = input.trim_end_matches(['\r', '\n'].as_ref());
-    trimmed.parse::<T>().map_err(InputError::Parse)
+/// A macro that behaves like [`input!`], but only prints the prompt when stdin
+/// is a terminal (see [`is_stdin_tty`]).
+///
+/// When input is piped in, nobody is there to read the prompt, and printing it
+/// would end up mixed into the program's output.
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::input_auto_prompt;
+///
+/// // `echo 42 | program` prints nothing but the result
+/// let number: i32 = input_auto_prompt!("Enter a number: ").unwrap();
+/// println!("{}", number * 2);
+/// ```
+#[macro_export]
+macro_rules! input_auto_prompt {
+    () => {{
+        $crate::input!()
+    }};
+    ($($arg:tt)*) => {{
+        $crate::read_input_from(
+            &mut ::std::io::stdin().lock(),
+            $crate::is_stdin_tty().then_some(format_args!($($arg)*)),
+            $crate::PrintStyle::Continue,
+            $crate::TrimStyle::Trim,
+        )
+    }};
 }
 
-/// A unified error type indicating either an I/O error, a parse error, or EOF.
-#[derive(Debug)]
-pub enum InputError<E> {
-    /// An I/O error occurred (e.g., closed stdin).
-    Io(io::Error),
-    /// Failed to parse the input into the desired type.
-    Parse(E),
-    /// EOF encountered (read_line returned 0).
-    Eof,
+/// A macro that:
+/// - prints the prompt followed by ` [y/N]: ` (or ` [Y/n]: ` when the default is `true`),
+/// - reads a yes/no answer (see [`read_boolean_from`] for the accepted spellings),
+/// - re-prompts if the answer isn't recognized,
+/// - returns the default if the user just presses Enter or EOF is encountered.
+///
+/// The default is `false` unless given as the second argument.
+/// Use [`confirm_from`] directly to treat EOF as an error instead.
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::confirm;
+///
+/// if confirm!("Delete all files?").unwrap() {
+///     println!("Deleting...");
+/// }
+///
+/// if confirm!("Continue?", true).unwrap() {
+///     println!("Continuing...");
+/// }
+/// ```
+#[macro_export]
+macro_rules! confirm {
+    ($prompt:expr $(,)?) => {
+        $crate::confirm!($prompt, false)
+    };
+    ($prompt:expr, $default:expr $(,)?) => {{
+        let default: bool = $default;
+        match $crate::confirm_from(
+            &mut ::std::io::stdin().lock(),
+            &mut ::std::io::stdout(),
+            format_args!("{}", $prompt),
+            default,
+        ) {
+            Err($crate::InputError::Eof) => Ok(default),
+            result => result,
+        }
+    }};
 }
 
-/// Defines how the prompt should be printed.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum PrintStyle {
-    /// Print the prompt without a trailing newline (uses `print!`).
-    Continue,
-    /// Print the prompt with a trailing newline (uses `println!`).
-    NewLine,
+/// A macro that:
+/// - optionally prints a prompt (with `print!`),
+/// - reads **one line** from stdin,
+/// - parses it as an integer of the given type in the given radix (base),
+/// - returns `Err(InputError::Parse(e))` if the input cannot be parsed.
+///
+/// See [`read_input_radix`].
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::input_radix;
+///
+/// let color = input_radix!(u32, 16, "Hex color: ").unwrap();
+/// ```
+#[macro_export]
+macro_rules! input_radix {
+    ($ty:ty, $radix:expr) => {{
+        $crate::read_input_radix::<_, $ty>(
+            &mut ::std::io::stdin().lock(),
+            None,
+            $crate::PrintStyle::Continue,
+            $radix,
+        )
+    }};
+    ($ty:ty, $radix:expr, $($arg:tt)*) => {{
+        $crate::read_input_radix::<_, $ty>(
+            &mut ::std::io::stdin().lock(),
+            Some(format_args!($($arg)*)),
+            $crate::PrintStyle::Continue,
+            $radix,
+        )
+    }};
 }
 
-impl<E: std::fmt::Display + std::fmt::Debug> std::fmt::Display for InputError<E> {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            InputError::Io(e) => write!(f, "I/O error: {}", e),
-            InputError::Parse(e) => write!(f, "Parse error: {}", e),
-            InputError::Eof => write!(f, "EOF encountered"),
-        }
-    }
+/// A macro that:
+/// - optionally prints a prompt (with `print!`),
+/// - reads **one line** from stdin and parses it,
+/// - returns `Err(InputError::OutOfRange { .. })` if the value is outside the range,
+/// - returns `Err(InputError::Parse(e))` if the input cannot be parsed.
+///
+/// See [`read_in_range`].
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::input_range;
+///
+/// let pick: u8 = input_range!(1..=10, "Pick 1-10: ").unwrap();
+/// let temperature: f64 = input_range!(-273.15.., "Temperature: ").unwrap();
+/// ```
+#[macro_export]
+macro_rules! input_range {
+    ($range:expr) => {{
+        $crate::read_in_range(
+            &mut ::std::io::stdin().lock(),
+            None,
+            $crate::PrintStyle::Continue,
+            $range,
+        )
+    }};
+    ($range:expr, $($arg:tt)*) => {{
+        $crate::read_in_range(
+            &mut ::std::io::stdin().lock(),
+            Some(format_args!($($arg)*)),
+            $crate::PrintStyle::Continue,
+            $range,
+        )
+    }};
+}
+
+/// A macro that:
+/// - optionally prints a prompt (with `print!`),
+/// - reads **one line** from stdin and trims surrounding whitespace,
+/// - returns `Err(InputError::Parse(msg))` if nothing but whitespace was typed,
+/// - otherwise returns the trimmed `String`.
+///
+/// See [`read_nonempty_string_from`].
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::nonempty_input;
+///
+/// let username = nonempty_input!("Username: ").unwrap();
+/// ```
+#[macro_export]
+macro_rules! nonempty_input {
+    () => {{
+        $crate::read_nonempty_string_from(
+            &mut ::std::io::stdin().lock(),
+            None,
+            $crate::PrintStyle::Continue,
+        )
+    }};
+    ($($arg:tt)*) => {{
+        $crate::read_nonempty_string_from(
+            &mut ::std::io::stdin().lock(),
+            Some(format_args!($($arg)*)),
+            $crate::PrintStyle::Continue,
+        )
+    }};
+}
+
+/// A macro that:
+/// - optionally prints a prompt (with `print!`),
+/// - reads **one line** from stdin,
+/// - parses every whitespace-separated token into the given type, collecting a `Vec`,
+/// - returns `Err(InputError::Parse(e))` for the first token that cannot be parsed.
+///
+/// An empty line yields an empty `Vec`. See [`read_vec_from`].
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::input_vec;
+///
+/// let numbers = input_vec!(i32, "Numbers: ").unwrap();
+/// println!("Sum: {}", numbers.iter().sum::<i32>());
+/// ```
+#[macro_export]
+macro_rules! input_vec {
+    ($ty:ty) => {{
+        $crate::read_vec_from::<_, $ty>(
+            &mut ::std::io::stdin().lock(),
+            None,
+            $crate::PrintStyle::Continue,
+        )
+    }};
+    ($ty:ty, $($arg:tt)*) => {{
+        $crate::read_vec_from::<_, $ty>(
+            &mut ::std::io::stdin().lock(),
+            Some(format_args!($($arg)*)),
+            $crate::PrintStyle::Continue,
+        )
+    }};
 }
 
-impl<E: std::fmt::Display + std::fmt::Debug> std::error::Error for InputError<E> {}
\ No newline at end of file
+/// A macro that:
+/// - reads `n` lines from stdin, optionally printing a prompt (with `print!`) before each,
+/// - trims each line and parses it, collecting a `Vec`,
+/// - stops at the first error, returning `Err(InputError::Eof)` or `Err(InputError::Parse(e))`.
+///
+/// A prompt written after `|i|` can use `i`, bound to the 1-based index of
+/// the value being read, among its format arguments.
+/// See [`read_n_inputs_from`].
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::input_collect;
+///
+/// let scores: Vec<u32> = input_collect!(3, |i| "Score #{}: ", i).unwrap();
+/// let more: Vec<u32> = input_collect!(2).unwrap();
+/// ```
+#[macro_export]
+macro_rules! input_collect {
+    ($n:expr) => {{
+        $crate::read_n_inputs_from(
+            &mut ::std::io::stdin().lock(),
+            None,
+            $crate::PrintStyle::Continue,
+            $n,
+        )
+    }};
+    ($n:expr, |$index:ident| $($arg:tt)+) => {{
+        let count: usize = $n;
+        let mut values = ::std::vec::Vec::new();
+        let mut result = Ok(());
+        for $index in 1..=count {
+            match $crate::read_input_from(
+                &mut ::std::io::stdin().lock(),
+                Some(format_args!($($arg)+)),
+                $crate::PrintStyle::Continue,
+                $crate::TrimStyle::Trim,
+            ) {
+                Ok(value) => values.push(value),
+                Err(e) => {
+                    result = Err(e);
+                    break;
+                }
+            }
+        }
+        result.map(|()| values)
+    }};
+    ($n:expr, $($arg:tt)*) => {{
+        $crate::read_n_inputs_from(
+            &mut ::std::io::stdin().lock(),
+            Some(format_args!($($arg)*)),
+            $crate::PrintStyle::Continue,
+            $n,
+        )
+    }};
+}
+
+/// A macro that:
+/// - optionally prints a prompt (with `print!`),
+/// - reads **one line** from stdin,
+/// - parses one whitespace-separated token into each of the given types, returning a tuple,
+/// - returns `Err(InputError::TokenCount { .. })` if the line holds too few or too many tokens,
+/// - returns `Err(InputError::Parse(msg))` naming the first token that cannot be parsed.
+///
+/// The types are separated from the prompt with a `;`. See [`read_tuple_from`].
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::input_tuple;
+///
+/// let (name, age, height) = input_tuple!(String, u8, f64; "Record: ").unwrap();
+/// ```
+#[macro_export]
+macro_rules! input_tuple {
+    ($($ty:ty),+ $(,)?) => {{
+        $crate::read_tuple_from::<_, ($($ty,)+)>(
+            &mut ::std::io::stdin().lock(),
+            None,
+            $crate::PrintStyle::Continue,
+        )
+    }};
+    ($($ty:ty),+ $(,)?; $($arg:tt)*) => {{
+        $crate::read_tuple_from::<_, ($($ty,)+)>(
+            &mut ::std::io::stdin().lock(),
+            Some(format_args!($($arg)*)),
+            $crate::PrintStyle::Continue,
+        )
+    }};
+}
+
+/// A macro that:
+/// - prints the prompt and the options numbered from 1 (with `println!`),
+/// - reads the user's choice from stdin,
+/// - returns the **zero-based** index of the chosen option,
+/// - returns `Err(InputError::Eof)` if EOF is encountered.
+///
+/// It has two forms, told apart by what comes first:
+/// - `select!("Pick one:", items)`, with a string literal first, re-prompts on
+///   invalid or out-of-range input (see [`select_from`]). An optional third
+///   argument is the index chosen when the user just presses Enter.
+/// - `select!(items, "Pick {}:", arg)` makes a single attempt and takes
+///   `format!`-style prompt arguments (see [`read_select_from`]).
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::select;
+///
+/// let colors = ["Red", "Green", "Blue"];
+/// let index = select!("Pick one:", &colors).unwrap();
+/// println!("You picked {}", colors[index]);
+///
+/// // Green is chosen on an empty line
+/// let index = select!("Pick one:", &colors, 1).unwrap();
+///
+/// // A single attempt; an invalid answer is returned as an error
+/// let index = select!(&colors, "Pick color #{}:", 2);
+/// ```
+#[macro_export]
+macro_rules! select {
+    ($prompt:literal, $items:expr $(,)?) => {{
+        $crate::select_from(
+            &mut ::std::io::stdin().lock(),
+            &mut ::std::io::stdout(),
+            format_args!("{}", $prompt),
+            $items,
+            None,
+        )
+    }};
+    ($prompt:literal, $items:expr, $default:expr $(,)?) => {{
+        $crate::select_from(
+            &mut ::std::io::stdin().lock(),
+            &mut ::std::io::stdout(),
+            format_args!("{}", $prompt),
+            $items,
+            Some($default),
+        )
+    }};
+    ($items:expr, $($arg:tt)+) => {{
+        $crate::read_select_from(
+            &mut ::std::io::stdin().lock(),
+            &mut ::std::io::stdout(),
+            $items,
+            format_args!($($arg)+),
+        )
+    }};
+}
+
+/// A macro that:
+/// - reads every remaining line from stdin until EOF,
+/// - returns them as a `Vec<String>`, without trailing newlines,
+/// - returns `Err(e)` with the `io::Error` if an IO error occurs.
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::input_all;
+///
+/// let lines = input_all!().unwrap();
+/// println!("Read {} lines", lines.len());
+/// ```
+#[macro_export]
+macro_rules! input_all {
+    () => {{
+        $crate::read_all_lines_from(&mut ::std::io::stdin().lock())
+    }};
+}
+
+/// A macro that:
+/// - reads exactly `n` lines from stdin,
+/// - returns them as a `Vec<String>`, without trailing newlines,
+/// - returns `Err(InputError::Eof)` if EOF is encountered first,
+/// - returns `Err(InputError::Io(e))` if an IO error occurs.
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::input_n;
+///
+/// let grid = input_n!(3).unwrap();
+/// println!("First row: {}", grid[0]);
+/// ```
+#[macro_export]
+macro_rules! input_n {
+    ($n:expr) => {{
+        $crate::read_n_lines(&mut ::std::io::stdin().lock(), $n)
+    }};
+}
+
+/// A macro that behaves like [`input!`], but:
+/// - returns `Ok(None)` if EOF is encountered,
+/// - returns `Ok(Some(value))` if the input was parsed,
+/// - returns `Err(InputError::Parse(e))` or `Err(InputError::Io(e))` otherwise.
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::input_opt;
+///
+/// let number: Option<i32> = input_opt!("Enter a number (Ctrl-D to skip): ").unwrap();
+/// match number {
+///     Some(n) => println!("You entered: {}", n),
+///     None => println!("No number given."),
+/// }
+/// ```
+#[macro_export]
+macro_rules! input_opt {
+    () => {{
+        $crate::read_optional(
+            &mut ::std::io::stdin().lock(),
+            None,
+            $crate::PrintStyle::Continue,
+        )
+    }};
+    ($($arg:tt)*) => {{
+        $crate::read_optional(
+            &mut ::std::io::stdin().lock(),
+            Some(format_args!($($arg)*)),
+            $crate::PrintStyle::Continue,
+        )
+    }};
+}
+
+/// A macro that behaves like [`input!`], but also:
+/// - runs the parsed value through a predicate,
+/// - returns `Err(InputError::Validation(msg))` if the predicate returns `false`.
+///
+/// The target type is inferred from the closure's parameter or the binding annotation.
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::input_validated;
+///
+/// let age = input_validated!("Age: ", |n: &u8| *n <= 120).unwrap();
+/// ```
+#[macro_export]
+macro_rules! input_validated {
+    ($prompt:expr, $predicate:expr $(,)?) => {{
+        $crate::read_validated_from(
+            &mut ::std::io::stdin().lock(),
+            Some(format_args!("{}", $prompt)),
+            $crate::PrintStyle::Continue,
+            $predicate,
+        )
+    }};
+}
+
+/// A macro that behaves like [`input!`], but re-prompts until the input parses:
+/// - prints the parse error and the prompt again after each failed attempt,
+/// - returns `Err(InputError::Eof)` if EOF is encountered, instead of looping forever,
+/// - returns `Err(InputError::Io(e))` if an IO error occurs.
+///
+/// With `max = n` as the first argument, at most `n` attempts are made;
+/// once reached, the last `Err(InputError::Parse(e))` is returned.
+/// See [`read_with_retry`] for details.
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::input_until;
+///
+/// // Keep asking until a number is entered
+/// let number: i32 = input_until!("Enter a number: ").unwrap();
+///
+/// // Give up after 3 attempts
+/// let number: Result<i32, _> = input_until!(max = 3, "Enter a number: ");
+/// ```
+#[macro_export]
+macro_rules! input_until {
+    () => {{
+        $crate::read_with_retry(
+            &mut ::std::io::stdin().lock(),
+            &mut ::std::io::stdout(),
+            None,
+            usize::MAX,
+        )
+    }};
+    (max = $max:expr) => {{
+        $crate::read_with_retry(
+            &mut ::std::io::stdin().lock(),
+            &mut ::std::io::stdout(),
+            None,
+            $max,
+        )
+    }};
+    (max = $max:expr, $($arg:tt)*) => {{
+        $crate::read_with_retry(
+            &mut ::std::io::stdin().lock(),
+            &mut ::std::io::stdout(),
+            Some(format_args!($($arg)*)),
+            $max,
+        )
+    }};
+    ($($arg:tt)*) => {{
+        $crate::read_with_retry(
+            &mut ::std::io::stdin().lock(),
+            &mut ::std::io::stdout(),
+            Some(format_args!($($arg)*)),
+            usize::MAX,
+        )
+    }};
+}
+
+/// A macro that behaves like [`input!`], but falls back to a default value on EOF.
+/// - `Parse` and `Io` errors are still returned.
+/// - Without an explicit value, `T::default()` is used (see [`read_or_default`]).
+/// - With `default = value` as the first argument, that value is used instead.
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::input_or_default;
+///
+/// // Falls back to 0 on EOF
+/// let count: u32 = input_or_default!("Count: ").unwrap();
+///
+/// // Falls back to 8080 on EOF
+/// let port: u16 = input_or_default!(default = 8080, "Port: ").unwrap();
+/// ```
+#[macro_export]
+macro_rules! input_or_default {
+    () => {{
+        $crate::read_or_default(
+            &mut ::std::io::stdin().lock(),
+            None,
+            $crate::PrintStyle::Continue,
+        )
+    }};
+    (default = $default:expr) => {{
+        $crate::read_or_else(
+            &mut ::std::io::stdin().lock(),
+            None,
+            $crate::PrintStyle::Continue,
+            || $default,
+        )
+    }};
+    (default = $default:expr, $($arg:tt)*) => {{
+        $crate::read_or_else(
+            &mut ::std::io::stdin().lock(),
+            Some(format_args!($($arg)*)),
+            $crate::PrintStyle::Continue,
+            || $default,
+        )
+    }};
+    ($($arg:tt)*) => {{
+        $crate::read_or_default(
+            &mut ::std::io::stdin().lock(),
+            Some(format_args!($($arg)*)),
+            $crate::PrintStyle::Continue,
+        )
+    }};
+}
+
+/// A macro that behaves like [`input!`], but returns `default` when the user
+/// just presses Enter or EOF is encountered.
+/// - The type of `default` decides what the input is parsed into.
+/// - `Parse` and `Io` errors are still returned.
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::input_or;
+///
+/// let port = input_or!(8080u16, "Port [8080]: ").unwrap();
+/// ```
+#[macro_export]
+macro_rules! input_or {
+    ($default:expr) => {{
+        $crate::read_with_default(
+            &mut ::std::io::stdin().lock(),
+            None,
+            $crate::PrintStyle::Continue,
+            $default,
+        )
+    }};
+    ($default:expr, $($arg:tt)*) => {{
+        $crate::read_with_default(
+            &mut ::std::io::stdin().lock(),
+            Some(format_args!($($arg)*)),
+            $crate::PrintStyle::Continue,
+            $default,
+        )
+    }};
+}
+
+/// A macro that behaves like [`input!`], but passes the parsed value through
+/// `transform` before returning it (see [`read_with_transform`]).
+/// - The closure's argument type decides what the input is parsed into.
+/// - `Parse`, `Eof` and `Io` errors are returned before `transform` runs.
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::input_map;
+///
+/// let code = input_map!("Country code: ", |s: String| s.to_uppercase()).unwrap();
+/// let cents = input_map!("Price in dollars: ", |d: u32| d * 100).unwrap();
+/// ```
+#[macro_export]
+macro_rules! input_map {
+    ($prompt:expr, $transform:expr $(,)?) => {{
+        $crate::read_with_transform(
+            &mut ::std::io::stdin().lock(),
+            Some(format_args!("{}", $prompt)),
+            $crate::PrintStyle::Continue,
+            $transform,
+        )
+    }};
+}
+
+/// A single function that:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line from the provided [`InputSource`], usually a `BufRead`.
+/// 3. Returns `Err(InputError::Eof)` if EOF is reached.
+/// 4. Trims the line according to `trim_style`.
+/// 5. Parses into type `T`, returning `Err(InputError::Parse)` on failure.
+/// 6. Returns `Err(InputError::Io)` on I/O failure.
+///
+/// A UTF-8 byte order mark at the start of the line is stripped before
+/// trimming (see [`strip_bom`]), so the first line of a file saved with one
+/// still parses. Each call reads a single line and can't tell whether it is the
+/// first, so to keep a `U+FEFF` starting a later line, read through
+/// [`StripBom`] with a function that doesn't strip it, like `BufRead::read_line`.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_input_from, PrintStyle, TrimStyle};
+///
+/// let mut reader = Cursor::new("  blue  \n");
+///
+/// // Prints "Favorite color?\n" to stdout before reading the line.
+/// let color: String = read_input_from(
+///     &mut reader,
+///     Some(format_args!("Favorite color?")),
+///     PrintStyle::NewLine,
+///     TrimStyle::Trim,
+/// ).unwrap();
+///
+/// assert_eq!(color, "blue");
+///
+/// // A file starting with a byte order mark still parses.
+/// let mut reader = Cursor::new("\u{FEFF}42\n");
+/// let n: i32 = read_input_from(&mut reader, None, PrintStyle::Continue, TrimStyle::Trim).unwrap();
+/// assert_eq!(n, 42);
+/// ```
+pub fn read_input_from<R, T>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    trim_style: TrimStyle,
+) -> Result<T, InputError<T::Err>>
+where
+    R: InputSource,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    match print_style {
+        PrintStyle::Stderr | PrintStyle::StderrNewLine => {
+            read_input_from_with_writer(reader, &mut io::stderr(), prompt, print_style, trim_style)
+        }
+        _ => read_input_from_with_writer(reader, &mut io::stdout(), prompt, print_style, trim_style),
+    }
+}
+
+/// Like [`read_input_from`], but prints the prompt to `writer` instead of stdout:
+/// 1. Optionally prints a prompt to `writer` (and flushes).
+/// 2. Reads one line from the provided [`InputSource`], usually a `BufRead`.
+/// 3. Returns `Err(InputError::Eof)` if EOF is reached.
+/// 4. Trims the line according to `trim_style`.
+/// 5. Parses into type `T`, returning `Err(InputError::Parse)` on failure.
+/// 6. Returns `Err(InputError::Io)` on I/O failure.
+///
+/// This is the I/O-agnostic core: [`read_input_from`], and through it the
+/// `input!` family of macros, call it with stdout or stderr as the writer.
+/// Passing a `Vec<u8>` as the writer captures exactly what was printed.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_input_from_with_writer, PrintStyle, TrimStyle};
+///
+/// let mut reader = Cursor::new("Alice\nblue\n");
+///
+/// let mut output = Vec::new();
+/// let name: String = read_input_from_with_writer(
+///     &mut reader,
+///     &mut output,
+///     Some(format_args!("Name: ")),
+///     PrintStyle::Continue,
+///     TrimStyle::TrailingNewline,
+/// ).unwrap();
+/// assert_eq!(name, "Alice");
+/// assert_eq!(output, b"Name: ");
+///
+/// let mut output = Vec::new();
+/// let color: String = read_input_from_with_writer(
+///     &mut reader,
+///     &mut output,
+///     Some(format_args!("Favorite color?")),
+///     PrintStyle::NewLine,
+///     TrimStyle::TrailingNewline,
+/// ).unwrap();
+/// assert_eq!(color, "blue");
+/// assert_eq!(output, b"Favorite color?\n");
+///
+/// // Stderr styles only pick the destination for `read_input_from`;
+/// // with an explicit writer, the prompt goes to that writer.
+/// let mut stderr = Vec::new();
+/// let _: Result<String, _> = read_input_from_with_writer(
+///     &mut reader,
+///     &mut stderr,
+///     Some(format_args!("Anything else?")),
+///     PrintStyle::StderrNewLine,
+///     TrimStyle::TrailingNewline,
+/// );
+/// assert_eq!(stderr, b"Anything else?\n");
+///
+/// // A byte order mark is stripped before trimming.
+/// let mut reader = Cursor::new("\u{FEFF}  42\r\n");
+/// let n: i32 = read_input_from_with_writer(&mut reader, &mut Vec::new(), None, PrintStyle::Continue, TrimStyle::Trim).unwrap();
+/// assert_eq!(n, 42);
+/// ```
+pub fn read_input_from_with_writer<R, W, T>(
+    reader: &mut R,
+    writer: &mut W,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    trim_style: TrimStyle,
+) -> Result<T, InputError<T::Err>>
+where
+    R: InputSource,
+    W: Write,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    if let Some(prompt_args) = prompt {
+        write_prompt(writer, prompt_args, print_style)?;
+    }
+
+    let mut input = String::new();
+    if InputSource::read_line(reader, &mut input)? == 0 {
+        return Err(InputError::Eof);
+    }
+    trim_style.apply(strip_bom(&input)).parse::<T>().map_err(InputError::Parse)
+}
+
+/// Removes a leading UTF-8 byte order mark (`U+FEFF`) from `s`, if there is one.
+///
+/// Files saved by some editors start with a BOM, which would otherwise end up
+/// in the first value parsed from them. [`read_input_from`] calls it on each
+/// line it reads; [`StripBom`] strips the mark from a stream instead.
+///
+/// # Usage:
+/// ```
+/// use input_lib::strip_bom;
+///
+/// assert_eq!(strip_bom("\u{FEFF}42"), "42");
+/// assert_eq!(strip_bom("42"), "42");
+/// ```
+pub fn strip_bom(s: &str) -> &str {
+    s.strip_prefix('\u{FEFF}').unwrap_or(s)
+}
+
+/// A `BufRead` adapter that drops a UTF-8 byte order mark at the very start of
+/// the stream, and passes everything after it through untouched.
+///
+/// Only the first read is checked, so a `U+FEFF` on any later line is kept.
+/// Every function taking a `BufRead` or an [`InputSource`] accepts the wrapped reader.
+///
+/// # Usage:
+/// ```
+/// use std::io::{BufRead, Cursor};
+/// use input_lib::StripBom;
+///
+/// let mut reader = StripBom::new(Cursor::new("\u{FEFF}42\n\u{FEFF}7\n"));
+///
+/// let mut first = String::new();
+/// reader.read_line(&mut first).unwrap();
+/// assert_eq!(first, "42\n");
+///
+/// let mut second = String::new();
+/// reader.read_line(&mut second).unwrap();
+/// assert_eq!(second, "\u{FEFF}7\n");
+/// ```
+#[derive(Debug)]
+pub struct StripBom<R> {
+    reader: R,
+    head: Vec<u8>,
+    pos: usize,
+    checked: bool,
+}
+
+impl<R: BufRead> StripBom<R> {
+    /// Wraps `reader`, which should be positioned at the start of the stream.
+    pub fn new(reader: R) -> Self {
+        StripBom { reader, head: Vec::new(), pos: 0, checked: false }
+    }
+
+    /// Returns the wrapped reader.
+    ///
+    /// The first bytes of the stream are read ahead to look for the mark, so
+    /// up to three of them may be dropped if nothing has been read yet.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+}
+
+impl<R: BufRead> Read for StripBom<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.consume(len);
+        Ok(len)
+    }
+}
+
+impl<R: BufRead> BufRead for StripBom<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if !self.checked {
+            // The mark may arrive over several short reads, so collect the
+            // first bytes until they either spell it out or stop matching.
+            const BOM: &[u8] = "\u{FEFF}".as_bytes();
+            while self.head.len() < BOM.len() && BOM.starts_with(&self.head) {
+                let available = self.reader.fill_buf()?;
+                if available.is_empty() {
+                    break;
+                }
+                let len = available.len().min(BOM.len() - self.head.len());
+                self.head.extend_from_slice(&available[..len]);
+                self.reader.consume(len);
+            }
+            if self.head == BOM {
+                self.head.clear();
+            }
+            self.checked = true;
+        }
+        if self.pos < self.head.len() {
+            return Ok(&self.head[self.pos..]);
+        }
+        self.reader.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        if self.pos < self.head.len() {
+            self.pos = (self.pos + amt).min(self.head.len());
+        } else {
+            self.reader.consume(amt)
+        }
+    }
+}
+
+/// Returns `true` if stdin is attached to a terminal, meaning a person is
+/// typing the input rather than it being piped in from a file or another program.
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::is_stdin_tty;
+///
+/// if is_stdin_tty() {
+///     println!("Type your answers below.");
+/// }
+/// ```
+pub fn is_stdin_tty() -> bool {
+    io::stdin().is_terminal()
+}
+
+/// Like [`read_input_from`], but parses the line with `parse` instead of `FromStr`:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line from the provided `BufRead`, stripping the line terminator.
+/// 3. Returns `Err(InputError::Eof)` if EOF is reached.
+/// 4. Calls `parse` on the line, returning `Err(InputError::Parse)` if it fails.
+/// 5. Returns `Err(InputError::Io)` on I/O failure.
+///
+/// This covers types without a `FromStr` impl and parsing that needs context,
+/// such as `serde_json::from_str` or a custom tokenizer.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_input_parse_with, InputError, PrintStyle};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Point {
+///     x: i32,
+///     y: i32,
+/// }
+///
+/// fn parse_point(line: &str) -> Result<Point, String> {
+///     let (x, y) = line.split_once(',').ok_or("expected 'x,y'")?;
+///     let x = x.trim().parse().map_err(|_| format!("bad x: '{}'", x))?;
+///     let y = y.trim().parse().map_err(|_| format!("bad y: '{}'", y))?;
+///     Ok(Point { x, y })
+/// }
+///
+/// let mut reader = Cursor::new("3, -4\n3\n");
+///
+/// let point = read_input_parse_with(&mut reader, None, PrintStyle::Continue, parse_point).unwrap();
+/// assert_eq!(point, Point { x: 3, y: -4 });
+///
+/// let missing = read_input_parse_with(&mut reader, None, PrintStyle::Continue, parse_point);
+/// assert!(matches!(missing, Err(InputError::Parse(msg)) if msg == "expected 'x,y'"));
+///
+/// // Closures can reject inputs that `FromStr` would accept.
+/// let mut reader = Cursor::new("root\n");
+/// let user = read_input_parse_with(&mut reader, None, PrintStyle::Continue, |line| match line {
+///     "root" => Err("reserved user name"),
+///     name => Ok(name.to_string()),
+/// });
+/// assert!(matches!(user, Err(InputError::Parse("reserved user name"))));
+/// ```
+pub fn read_input_parse_with<R, T, E, F>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    parse: F,
+) -> Result<T, InputError<E>>
+where
+    R: BufRead,
+    F: FnOnce(&str) -> Result<T, E>,
+{
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let input = read_line(reader, TrimStyle::Trim)?.ok_or(InputError::Eof)?;
+    parse(&input).map_err(InputError::Parse)
+}
+
+/// Like [`read_input_from`], but maps the parsed value with `transform`:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line, trims surrounding whitespace and parses it into `T`.
+/// 3. Returns `transform(value)`.
+///
+/// `Eof`, `Parse` and `Io` errors are returned as-is, without calling `transform`.
+/// This saves an intermediate binding when the type read differs from the type kept.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_with_transform, PrintStyle};
+///
+/// let mut reader = Cursor::new("  alice \n90\n");
+///
+/// let name = read_with_transform(&mut reader, None, PrintStyle::Continue, |s: String| s.to_uppercase()).unwrap();
+/// assert_eq!(name, "ALICE");
+///
+/// let seconds = read_with_transform(&mut reader, None, PrintStyle::Continue, std::time::Duration::from_secs).unwrap();
+/// assert_eq!(seconds.as_secs(), 90);
+/// ```
+pub fn read_with_transform<R, T, U, F>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    transform: F,
+) -> Result<U, InputError<T::Err>>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+    F: FnOnce(T) -> U,
+{
+    read_input_from(reader, prompt, print_style, TrimStyle::Trim).map(transform)
+}
+
+/// Like [`read_input_from`], but rejects blank lines:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line from the provided `BufRead`.
+/// 3. Returns `Err(InputError::Empty)` if the line is empty or whitespace-only.
+/// 4. Otherwise parses into type `T`, exactly like [`read_input_from`].
+///
+/// Use this instead of [`read_input_from`] when an empty answer is never valid,
+/// so blank lines get a clear error instead of an opaque parse failure.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_nonempty_from, InputError, PrintStyle};
+///
+/// let mut reader = Cursor::new("\n   \t\n7\n");
+///
+/// let bare: Result<u32, _> = read_nonempty_from(&mut reader, None, PrintStyle::Continue);
+/// assert!(matches!(bare, Err(InputError::Empty)));
+///
+/// let blank: Result<u32, _> = read_nonempty_from(&mut reader, None, PrintStyle::Continue);
+/// assert!(matches!(blank, Err(InputError::Empty)));
+///
+/// let number: u32 = read_nonempty_from(&mut reader, None, PrintStyle::Continue).unwrap();
+/// assert_eq!(number, 7);
+/// ```
+pub fn read_nonempty_from<R, T>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+) -> Result<T, InputError<T::Err>>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let input = read_line(reader, TrimStyle::Trim)?.ok_or(InputError::Eof)?;
+    if input.trim().is_empty() {
+        return Err(InputError::Empty);
+    }
+    input.parse::<T>().map_err(InputError::Parse)
+}
+
+/// Like [`read_nonempty_from`], but skips blank lines instead of failing on them:
+/// 1. Optionally prints a prompt once (and flushes).
+/// 2. Reads lines, discarding blank ones and, if `skip_comments` is set, ones starting with `#`.
+/// 3. Trims the first remaining line and parses it into `T`, returning `Err(InputError::Parse)` on failure.
+/// 4. Returns `Err(InputError::Eof)` if EOF is reached first.
+///
+/// Handy for scripted input files with comments. Leading whitespace before `#`
+/// is ignored; a `#` later in the line is part of the value.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_input_skip, InputError, PrintStyle};
+///
+/// let mut reader = Cursor::new("# width\n\n  80\n\n   # height\n24\n# done\n");
+///
+/// let width: u32 = read_input_skip(&mut reader, None, PrintStyle::Continue, true).unwrap();
+/// let height: u32 = read_input_skip(&mut reader, None, PrintStyle::Continue, true).unwrap();
+/// let rest: Result<u32, _> = read_input_skip(&mut reader, None, PrintStyle::Continue, true);
+///
+/// assert_eq!((width, height), (80, 24));
+/// assert!(matches!(rest, Err(InputError::Eof)));
+///
+/// // Without `skip_comments`, only blank lines are skipped.
+/// let mut reader = Cursor::new("\n#hashtag\n");
+/// let tag: String = read_input_skip(&mut reader, None, PrintStyle::Continue, false).unwrap();
+/// assert_eq!(tag, "#hashtag");
+/// ```
+pub fn read_input_skip<R, T>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    skip_comments: bool,
+) -> Result<T, InputError<T::Err>>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    let prefix = if skip_comments { "#" } else { "" };
+    let mut source = SkipComments { source: reader, prefix, skip_blank: true };
+    read_input_from(&mut source, prompt, print_style, TrimStyle::Trim)
+}
+
+/// Like [`read_input_from`], but skips comment lines:
+/// 1. Optionally prints a prompt once (and flushes).
+/// 2. Reads lines, discarding those that start with `comment_prefix` after leading whitespace.
+/// 3. Trims the first remaining line and parses it into `T`, returning `Err(InputError::Parse)` on failure.
+/// 4. Returns `Err(InputError::Eof)` if EOF is reached first.
+///
+/// Blank lines are not skipped; see [`read_input_skip`] for that. An empty
+/// `comment_prefix` disables skipping. For repeated reads, see [`InputReader::skip_comments`].
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_skip_comments_from, PrintStyle};
+///
+/// let mut reader = Cursor::new("// retries\n  // (0 disables)\n3\n");
+/// let retries: u8 = read_skip_comments_from(&mut reader, None, PrintStyle::Continue, "//").unwrap();
+/// assert_eq!(retries, 3);
+/// ```
+pub fn read_skip_comments_from<R, T>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    comment_prefix: &str,
+) -> Result<T, InputError<T::Err>>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    let mut source = SkipComments { source: reader, prefix: comment_prefix, skip_blank: false };
+    read_input_from(&mut source, prompt, print_style, TrimStyle::Trim)
+}
+
+/// Reads `n` values, one per line:
+/// 1. Optionally prints the prompt (and flushes) before each line.
+/// 2. Reads one line, trims surrounding whitespace and parses it into `T`.
+/// 3. Stops at the first error, returning `Err(InputError::Eof)` if EOF is
+///    reached early and `Parse`/`Io` errors like [`read_input_from`].
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_n_inputs_from, InputError, PrintStyle};
+///
+/// let mut reader = Cursor::new("3\n 1 \n4\n1\n");
+///
+/// let n: usize = read_n_inputs_from(&mut reader, None, PrintStyle::Continue, 1).unwrap()[0];
+/// let values: Vec<i64> = read_n_inputs_from(&mut reader, None, PrintStyle::Continue, n).unwrap();
+/// assert_eq!(values, [1, 4, 1]);
+///
+/// let short = read_n_inputs_from::<_, i64>(&mut reader, None, PrintStyle::Continue, 2);
+/// assert!(matches!(short, Err(InputError::Eof)));
+/// ```
+pub fn read_n_inputs_from<R, T>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    n: usize,
+) -> Result<Vec<T>, InputError<T::Err>>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    (0..n).map(|_| read_input_from(reader, prompt, print_style, TrimStyle::Trim)).collect()
+}
+
+/// Like [`read_input_from`], but treats EOF as a clean end of input:
+/// 1. Returns `Ok(None)` if EOF is reached.
+/// 2. Returns `Ok(Some(value))` if the line was parsed into `T`.
+/// 3. Returns `Err(InputError::Parse)` or `Err(InputError::Io)` unchanged.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_optional, PrintStyle};
+///
+/// let mut reader = Cursor::new("1\n2\n");
+/// let mut sum = 0;
+/// while let Some(n) = read_optional::<_, i32>(&mut reader, None, PrintStyle::Continue).unwrap() {
+///     sum += n;
+/// }
+///
+/// assert_eq!(sum, 3);
+/// ```
+pub fn read_optional<R, T>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+) -> Result<Option<T>, InputError<T::Err>>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    match read_input_from(reader, prompt, print_style, TrimStyle::Trim) {
+        Ok(value) => Ok(Some(value)),
+        Err(InputError::Eof) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Like [`read_optional`], but also treats a blank line as no answer:
+/// 1. Returns `Ok(None)` if EOF is reached or the line is empty or whitespace-only.
+/// 2. Returns `Ok(Some(value))` if the line was parsed into `T`.
+/// 3. Returns `Err(InputError::Parse)` or `Err(InputError::Io)` unchanged.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_optional_from, PrintStyle};
+///
+/// let mut reader = Cursor::new("42\n  \nabc\n");
+///
+/// assert_eq!(read_optional_from::<_, u8>(&mut reader, None, PrintStyle::Continue).unwrap(), Some(42));
+/// assert_eq!(read_optional_from::<_, u8>(&mut reader, None, PrintStyle::Continue).unwrap(), None);
+/// assert!(read_optional_from::<_, u8>(&mut reader, None, PrintStyle::Continue).unwrap_err().is_parse());
+/// assert_eq!(read_optional_from::<_, u8>(&mut reader, None, PrintStyle::Continue).unwrap(), None);
+/// ```
+pub fn read_optional_from<R, T>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+) -> Result<Option<T>, InputError<T::Err>>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    match read_nonempty_from(reader, prompt, print_style) {
+        Ok(value) => Ok(Some(value)),
+        Err(InputError::Eof | InputError::Empty) => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+/// Turns the result of a read into an `Option`, for loops that read until EOF:
+/// 1. Returns `Some(value)` if the read succeeded.
+/// 2. Returns `None` if EOF was reached.
+///
+/// # Panics
+///
+/// Panics with the error's message on any other error, such as `Parse` or `Io`.
+/// Use [`read_optional`] to handle those errors instead.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{input_to_option, read_input_from, PrintStyle, TrimStyle};
+///
+/// let mut reader = Cursor::new("1\n2\n3\n");
+/// let mut sum = 0;
+/// while let Some(n) = input_to_option(read_input_from::<_, i32>(&mut reader, None, PrintStyle::Continue, TrimStyle::Trim)) {
+///     sum += n;
+/// }
+/// assert_eq!(sum, 6);
+/// ```
+///
+/// ```should_panic
+/// use std::io::Cursor;
+/// use input_lib::{input_to_option, read_input_from, PrintStyle, TrimStyle};
+///
+/// let mut reader = Cursor::new("abc\n");
+/// input_to_option(read_input_from::<_, i32>(&mut reader, None, PrintStyle::Continue, TrimStyle::Trim));
+/// ```
+#[track_caller]
+pub fn input_to_option<T, E>(result: Result<T, InputError<E>>) -> Option<T>
+where
+    E: std::fmt::Display + std::fmt::Debug,
+{
+    match result {
+        Ok(value) => Some(value),
+        Err(InputError::Eof) => None,
+        Err(e) => panic!("failed to read input: {}", e),
+    }
+}
+
+/// Like [`read_input_from`], but returns `T::default()` if EOF is reached.
+///
+/// `Parse` and `Io` errors are still returned, so genuine problems aren't hidden.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_or_default, PrintStyle};
+///
+/// let mut reader = Cursor::new("5\n");
+///
+/// let first: u32 = read_or_default(&mut reader, None, PrintStyle::Continue).unwrap();
+/// let second: u32 = read_or_default(&mut reader, None, PrintStyle::Continue).unwrap();
+///
+/// assert_eq!((first, second), (5, 0));
+/// ```
+pub fn read_or_default<R, T>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+) -> Result<T, InputError<T::Err>>
+where
+    R: BufRead,
+    T: FromStr + Default,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    read_or_else(reader, prompt, print_style, T::default)
+}
+
+/// Like [`read_input_from`], but returns the value computed by `default` if EOF is reached.
+///
+/// `Parse` and `Io` errors are still returned, so genuine problems aren't hidden.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_or_else, PrintStyle};
+///
+/// let port: u16 = read_or_else(&mut Cursor::new(""), None, PrintStyle::Continue, || 8080).unwrap();
+/// assert_eq!(port, 8080);
+/// ```
+pub fn read_or_else<R, T, F>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    default: F,
+) -> Result<T, InputError<T::Err>>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+    F: FnOnce() -> T,
+{
+    match read_input_from(reader, prompt, print_style, TrimStyle::Trim) {
+        Err(InputError::Eof) => Ok(default()),
+        result => result,
+    }
+}
+
+/// Like [`read_input_from`], but returns `default` if the line is empty
+/// (or whitespace-only) or EOF is reached.
+///
+/// `Parse` and `Io` errors are still returned.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_with_default, PrintStyle};
+///
+/// let mut reader = Cursor::new("\n3000\n");
+///
+/// let empty = read_with_default(&mut reader, None, PrintStyle::Continue, 8080u16).unwrap();
+/// let given = read_with_default(&mut reader, None, PrintStyle::Continue, 8080u16).unwrap();
+/// let eof = read_with_default(&mut reader, None, PrintStyle::Continue, 8080u16).unwrap();
+///
+/// assert_eq!((empty, given, eof), (8080, 3000, 8080));
+///
+/// let invalid = read_with_default(&mut Cursor::new("http\n"), None, PrintStyle::Continue, 8080u16);
+/// assert!(invalid.unwrap_err().is_parse());
+/// ```
+pub fn read_with_default<R, T>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    default: T,
+) -> Result<T, InputError<T::Err>>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    match read_nonempty_from(reader, prompt, print_style) {
+        Err(InputError::Empty | InputError::Eof) => Ok(default),
+        result => result,
+    }
+}
+
+/// Like [`read_with_default`], but shows the default in the prompt:
+/// 1. Prints `"{prefix} [{default}]: "` (and flushes).
+/// 2. Reads one line from the provided `BufRead`.
+/// 3. Returns `default` if the line is empty (or whitespace-only) or EOF is reached.
+/// 4. Otherwise parses into type `T`, returning `Err(InputError::Parse)` on failure.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_with_default_display, PrintStyle};
+///
+/// let mut reader = Cursor::new("\n60\n");
+///
+/// let timeout = read_with_default_display(&mut reader, format_args!("Enter timeout"), PrintStyle::Continue, 30);
+/// assert_eq!(timeout.unwrap(), 30);
+///
+/// let timeout = read_with_default_display(&mut reader, format_args!("Enter timeout"), PrintStyle::Continue, 30);
+/// assert_eq!(timeout.unwrap(), 60);
+/// ```
+pub fn read_with_default_display<R, T>(
+    reader: &mut R,
+    prefix: Arguments<'_>,
+    print_style: PrintStyle,
+    default: T,
+) -> Result<T, InputError<T::Err>>
+where
+    R: BufRead,
+    T: FromStr + std::fmt::Display,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    let prompt = format!("{} [{}]: ", prefix, default);
+    read_with_default(reader, Some(format_args!("{}", prompt)), print_style, default)
+}
+
+/// Like [`read_input_from`], but gives up if no full line arrives within `timeout`:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line from `reader` on a background thread.
+/// 3. Returns `Err(InputError::Timeout(timeout))` if the line doesn't arrive in time.
+/// 4. Otherwise behaves like [`read_input_from`] (`Eof`, `Parse` and `Io` errors).
+///
+/// `BufRead` can't be interrupted, so on timeout the background thread is left
+/// blocked on the read and `reader` is not returned. Each timed-out call leaks
+/// that thread until the read finishes.
+///
+/// # Usage:
+/// ```
+/// use std::io::BufReader;
+/// use std::time::Duration;
+/// use input_lib::{read_input_with_timeout, InputError, PrintStyle};
+///
+/// // A pipe that never receives any data
+/// let (reader, _writer) = std::io::pipe().unwrap();
+///
+/// let result: Result<String, _> = read_input_with_timeout(
+///     BufReader::new(reader),
+///     None,
+///     PrintStyle::Continue,
+///     Duration::from_millis(50),
+/// );
+///
+/// assert!(matches!(result, Err(InputError::Timeout(d)) if d == Duration::from_millis(50)));
+/// ```
+pub fn read_input_with_timeout<R, T>(
+    mut reader: R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    timeout: Duration,
+) -> Result<T, InputError<T::Err>>
+where
+    R: BufRead + Send + 'static,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let input = read_line_on_thread(move || read_line(&mut reader, TrimStyle::Trim), timeout)?;
+    input.parse::<T>().map_err(InputError::Parse)
+}
+
+/// Like [`read_input_with_timeout`], but reads from stdin.
+///
+/// The same trade-off applies: on timeout, a background thread stays blocked
+/// reading stdin, and the line it eventually reads is lost to later reads.
+///
+/// # Usage:
+/// ```no_run
+/// use std::time::Duration;
+/// use input_lib::{read_stdin_with_timeout, InputError, PrintStyle};
+///
+/// match read_stdin_with_timeout::<u32>(Some(format_args!("PIN: ")), PrintStyle::Continue, Duration::from_secs(30)) {
+///     Ok(pin) => println!("Got {}", pin),
+///     Err(InputError::Timeout(_)) => println!("Too slow!"),
+///     Err(e) => println!("{}", e),
+/// }
+/// ```
+pub fn read_stdin_with_timeout<T>(
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    timeout: Duration,
+) -> Result<T, InputError<T::Err>>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let input = read_line_on_thread(|| read_line(&mut io::stdin().lock(), TrimStyle::Trim), timeout)?;
+    input.parse::<T>().map_err(InputError::Parse)
+}
+
+/// Reads a value from the environment variable `var_name`, or from stdin if it isn't set:
+/// 1. If `var_name` is set, parses its value with [`read_from_str`], without prompting.
+/// 2. Otherwise optionally prints a prompt (and flushes) and reads one line from stdin,
+///    trimming surrounding whitespace like [`input!`].
+/// 3. Returns `Err(InputError::Parse)` if the value can't be parsed, wherever it came from.
+///
+/// A variable that is set but isn't valid Unicode is reported as an
+/// `Err(InputError::Io)` with `ErrorKind::InvalidData`, rather than falling back to stdin.
+///
+/// # Usage:
+/// ```
+/// use input_lib::{read_from_env_or_stdin, PrintStyle};
+///
+/// // SAFETY: no other thread is reading the environment.
+/// unsafe { std::env::set_var("INPUT_LIB_DOC_PORT", " 8080 ") };
+///
+/// let port: u16 = read_from_env_or_stdin("INPUT_LIB_DOC_PORT", Some(format_args!("Port: ")), PrintStyle::Continue).unwrap();
+/// assert_eq!(port, 8080);
+///
+/// unsafe { std::env::set_var("INPUT_LIB_DOC_PORT", "http") };
+/// let port = read_from_env_or_stdin::<u16>("INPUT_LIB_DOC_PORT", None, PrintStyle::Continue);
+/// assert!(port.unwrap_err().is_parse());
+/// ```
+pub fn read_from_env_or_stdin<T>(
+    var_name: &str,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+) -> Result<T, InputError<T::Err>>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    match std::env::var(var_name) {
+        Ok(value) => read_from_str(&value),
+        Err(std::env::VarError::NotPresent) => {
+            read_input_from(&mut io::stdin().lock(), prompt, print_style, TrimStyle::Trim)
+        }
+        Err(e @ std::env::VarError::NotUnicode(_)) => Err(InputError::Io(io::Error::new(io::ErrorKind::InvalidData, e))),
+    }
+}
+
+/// Reads a yes/no answer:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line and trims surrounding whitespace.
+/// 3. Returns `Ok(true)` for `yes`, `y`, `true` or `1`, and `Ok(false)` for
+///    `no`, `n`, `false` or `0`, ignoring case.
+/// 4. Returns `Err(InputError::Empty)` for a blank line, `Err(InputError::Parse(msg))`
+///    for any other answer, and `Eof`/`Io` errors like [`read_input_from`].
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_boolean_from, InputError, PrintStyle};
+///
+/// let mut reader = Cursor::new("Yes\nn\nTRUE\n0\nmaybe\n");
+/// let mut answers = Vec::new();
+/// for _ in 0..4 {
+///     answers.push(read_boolean_from(&mut reader, None, PrintStyle::Continue).unwrap());
+/// }
+/// assert_eq!(answers, [true, false, true, false]);
+///
+/// let invalid = read_boolean_from(&mut reader, None, PrintStyle::Continue);
+/// assert!(matches!(invalid, Err(InputError::Parse(_))));
+/// ```
+pub fn read_boolean_from<R: BufRead>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+) -> Result<bool, InputError<String>> {
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let input = read_line(reader, TrimStyle::Trim)?.ok_or(InputError::Eof)?;
+    if input.is_empty() {
+        return Err(InputError::Empty);
+    }
+    parse_yes_no(&input)
+        .ok_or_else(|| InputError::Parse(format!("'{}' is not a valid yes/no answer", input)))
+}
+
+/// Reads a single-character answer:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line from the provided `BufRead`.
+/// 3. Returns the first character that isn't whitespace.
+/// 4. Returns `Err(InputError::Empty)` for a blank line, and `Eof`/`Io` errors
+///    like [`read_input_from`].
+///
+/// The rest of the line is consumed and discarded, so reads stay line-oriented:
+/// the next read starts on the following line. Use [`read_char_raw_from`] to
+/// get the first character even if it is whitespace.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_char_from, InputError, PrintStyle};
+///
+/// let mut reader = Cursor::new("y\nquit\n  q!\n\n \n");
+///
+/// assert_eq!(read_char_from(&mut reader, None, PrintStyle::Continue).unwrap(), 'y');
+/// assert_eq!(read_char_from(&mut reader, None, PrintStyle::Continue).unwrap(), 'q');
+/// assert_eq!(read_char_from(&mut reader, None, PrintStyle::Continue).unwrap(), 'q');
+/// assert!(matches!(read_char_from(&mut reader, None, PrintStyle::Continue), Err(InputError::Empty)));
+/// assert!(matches!(read_char_from(&mut reader, None, PrintStyle::Continue), Err(InputError::Empty)));
+/// assert!(matches!(read_char_from(&mut reader, None, PrintStyle::Continue), Err(InputError::Eof)));
+/// ```
+pub fn read_char_from<R: BufRead>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+) -> Result<char, InputError<String>> {
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let input = read_line(reader, TrimStyle::TrailingNewline)?.ok_or(InputError::Eof)?;
+    input.chars().find(|c| !c.is_whitespace()).ok_or(InputError::Empty)
+}
+
+/// Like [`read_char_from`], but returns the very first character of the line,
+/// whitespace included.
+///
+/// An empty line yields its line terminator (`'\n'`, or `'\r'` for `\r\n`).
+/// The rest of the line is discarded.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_char_raw_from, PrintStyle};
+///
+/// let mut reader = Cursor::new("  q!\n\n");
+///
+/// assert_eq!(read_char_raw_from(&mut reader, None, PrintStyle::Continue).unwrap(), ' ');
+/// assert_eq!(read_char_raw_from(&mut reader, None, PrintStyle::Continue).unwrap(), '\n');
+/// ```
+pub fn read_char_raw_from<R: BufRead>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+) -> Result<char, InputError<String>> {
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let mut input = String::new();
+    reader.read_line(&mut input)?;
+    input.chars().next().ok_or(InputError::Eof)
+}
+
+/// Asks a yes/no question until it gets a valid answer:
+/// 1. Prints the prompt to `writer`, followed by ` [y/N]: ` or ` [Y/n]: ` depending on `default`.
+/// 2. Reads one line (see [`read_boolean_from`] for the accepted spellings).
+/// 3. Returns `default` if the line is empty.
+/// 4. On an unrecognized answer, prints an error and asks again.
+/// 5. Returns `Err(InputError::Eof)` if EOF is reached, or `Err(InputError::Io)` on I/O failure.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{confirm_from, InputError};
+///
+/// let mut reader = Cursor::new("YES\nmaybe\nn\n\n");
+/// let mut output = Vec::new();
+///
+/// assert!(confirm_from(&mut reader, &mut output, format_args!("Continue?"), false).unwrap());
+/// assert!(!confirm_from(&mut reader, &mut output, format_args!("Continue?"), true).unwrap());
+/// assert!(confirm_from(&mut reader, &mut output, format_args!("Continue?"), true).unwrap());
+///
+/// let eof = confirm_from(&mut reader, &mut output, format_args!("Continue?"), true);
+/// assert!(matches!(eof, Err(InputError::Eof)));
+///
+/// assert!(String::from_utf8(output).unwrap().starts_with(
+///     "Continue? [y/N]: Continue? [Y/n]: 'maybe' is not a valid yes/no answer\nContinue? [Y/n]: "
+/// ));
+/// ```
+pub fn confirm_from<R, W>(
+    reader: &mut R,
+    writer: &mut W,
+    prompt: Arguments<'_>,
+    default: bool,
+) -> Result<bool, InputError<String>>
+where
+    R: BufRead,
+    W: Write,
+{
+    let choices = if default { "[Y/n]" } else { "[y/N]" };
+    retry_with(writer, usize::MAX, |writer| {
+        write_prompt(writer, format_args!("{} {}: ", prompt, choices), PrintStyle::Continue)?;
+        match read_boolean_from(reader, None, PrintStyle::Continue) {
+            Err(InputError::Empty) => Ok(default),
+            result => result,
+        }
+    })
+}
+
+/// Like [`read_input_from`], but only accepts one of the `allowed` values:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line and parses it into `T`.
+/// 3. Returns `Err(InputError::Parse(msg))` if parsing fails or the value is not in `allowed`.
+///
+/// The parse error is turned into a `String`, so both failures share one error type.
+/// Wrap the call in [`retry_with`] to re-prompt on invalid choices.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_one_of, InputError, PrintStyle};
+///
+/// let mut reader = Cursor::new("3\n5\n");
+///
+/// let size = read_one_of(&mut reader, None, PrintStyle::Continue, &[1, 3, 5]).unwrap();
+/// assert_eq!(size, 3);
+///
+/// let size = read_one_of(&mut reader, None, PrintStyle::Continue, &[2, 4]);
+/// assert!(matches!(size, Err(InputError::Parse(msg)) if msg == "'5' is not one of the accepted values"));
+/// ```
+pub fn read_one_of<R, T>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    allowed: &[T],
+) -> Result<T, InputError<String>>
+where
+    R: BufRead,
+    T: FromStr + PartialEq,
+    T::Err: std::fmt::Display,
+{
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let input = read_line(reader, TrimStyle::Trim)?.ok_or(InputError::Eof)?;
+    let value = input.parse::<T>().map_err(|e| InputError::Parse(e.to_string()))?;
+    if !allowed.contains(&value) {
+        return Err(InputError::Parse(format!("'{}' is not one of the accepted values", input)));
+    }
+    Ok(value)
+}
+
+/// Reads one line of whitespace-separated values:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line from the provided `BufRead`.
+/// 3. Splits it on ASCII whitespace and parses every token into `T`.
+/// 4. Returns `Err(InputError::Parse)` for the first token that fails to parse.
+///
+/// Runs of whitespace count as a single separator, and an empty line yields an empty `Vec`.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_vec_from, PrintStyle};
+///
+/// let mut reader = Cursor::new("3   14 159\n\n1 x 2\n");
+///
+/// let numbers: Vec<i32> = read_vec_from(&mut reader, None, PrintStyle::Continue).unwrap();
+/// assert_eq!(numbers, [3, 14, 159]);
+///
+/// let empty: Vec<i32> = read_vec_from(&mut reader, None, PrintStyle::Continue).unwrap();
+/// assert!(empty.is_empty());
+///
+/// let invalid: Result<Vec<i32>, _> = read_vec_from(&mut reader, None, PrintStyle::Continue);
+/// assert!(invalid.unwrap_err().is_parse());
+/// ```
+pub fn read_vec_from<R, T>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+) -> Result<Vec<T>, InputError<T::Err>>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let input = read_line(reader, TrimStyle::TrailingNewline)?.ok_or(InputError::Eof)?;
+    input
+        .split_ascii_whitespace()
+        .map(|token| token.parse::<T>().map_err(InputError::Parse))
+        .collect()
+}
+
+/// Like [`read_vec_from`], but splits the line on `delimiter` instead of whitespace:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line from the provided `BufRead`.
+/// 3. Splits it on `delimiter`, trims each piece and parses it into `T`.
+/// 4. Returns `Err(InputError::Parse)` for the first piece that fails to parse.
+///
+/// An empty piece after a trailing delimiter is ignored, so an empty line
+/// yields an empty `Vec`. See [`read_delimited_from`] for errors that name the piece.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_vec_delimited_from, PrintStyle};
+///
+/// let mut reader = Cursor::new("1, 2 ,3\n");
+///
+/// let numbers: Vec<i32> = read_vec_delimited_from(&mut reader, None, PrintStyle::Continue, ',').unwrap();
+/// assert_eq!(numbers, [1, 2, 3]);
+/// ```
+pub fn read_vec_delimited_from<R, T>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    delimiter: char,
+) -> Result<Vec<T>, InputError<T::Err>>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    read_pieces(reader, prompt, print_style, delimiter, |_, piece| piece.parse::<T>().map_err(InputError::Parse))
+}
+
+/// Like [`read_vec_delimited_from`], but reports which piece failed to parse:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line and splits it on `delimiter`, trimming each piece.
+/// 3. Ignores an empty piece after a trailing delimiter, so an empty line yields an empty `Vec`.
+/// 4. Parses every piece into `T`, returning `Err(InputError::Parse(msg))` for
+///    the first failure, where `msg` holds the piece's 1-based position and text.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_delimited_from, InputError, PrintStyle};
+///
+/// let mut reader = Cursor::new("10 | 20 | 30 | \n1,x,3\n");
+///
+/// let numbers: Vec<u32> = read_delimited_from(&mut reader, None, PrintStyle::Continue, '|').unwrap();
+/// assert_eq!(numbers, [10, 20, 30]);
+///
+/// let invalid = read_delimited_from::<_, u32>(&mut reader, None, PrintStyle::Continue, ',');
+/// match invalid {
+///     Err(InputError::Parse(msg)) => assert!(msg.starts_with("value 2 ('x')")),
+///     other => panic!("unexpected result: {:?}", other),
+/// }
+/// ```
+pub fn read_delimited_from<R, T>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    delimiter: char,
+) -> Result<Vec<T>, InputError<String>>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    read_pieces(reader, prompt, print_style, delimiter, parse_token)
+}
+
+/// Reads one line, splits it on `delimiter` and parses each trimmed piece with
+/// `parse`, which gets the piece's 1-based position. An empty piece after a
+/// trailing delimiter is ignored, so an empty line yields an empty `Vec`.
+fn read_pieces<R, T, E, F>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    delimiter: char,
+    mut parse: F,
+) -> Result<Vec<T>, InputError<E>>
+where
+    R: BufRead,
+    F: FnMut(usize, &str) -> Result<T, InputError<E>>,
+{
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let input = read_line(reader, TrimStyle::TrailingNewline)?.ok_or(InputError::Eof)?;
+    let mut pieces: Vec<&str> = input.split(delimiter).map(str::trim).collect();
+    if pieces.last() == Some(&"") {
+        pieces.pop();
+    }
+    pieces
+        .into_iter()
+        .enumerate()
+        .map(|(index, piece)| parse(index + 1, piece))
+        .collect()
+}
+
+/// Like [`read_input_from`], but a record ends at `delimiter` instead of a newline:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads bytes up to and including `delimiter` (or up to EOF for the last record).
+/// 3. Strips the delimiter and parses the rest into `T`.
+/// 4. Returns `Err(InputError::Eof)` if nothing is left to read.
+/// 5. Returns `Err(InputError::Io)` on I/O failure, or if the record isn't valid UTF-8.
+///
+/// Nothing else is trimmed, so newlines inside a record are kept.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_until_delimiter_from, InputError, PrintStyle};
+///
+/// let mut reader = Cursor::new("alice\0bob\0carol");
+/// let mut names = Vec::new();
+/// loop {
+///     match read_until_delimiter_from::<_, String>(&mut reader, None, PrintStyle::Continue, b'\0') {
+///         Ok(name) => names.push(name),
+///         Err(InputError::Eof) => break,
+///         Err(e) => panic!("unexpected error: {e}"),
+///     }
+/// }
+/// assert_eq!(names, ["alice", "bob", "carol"]);
+///
+/// let mut reader = Cursor::new("1;22;333;");
+/// let first: u32 = read_until_delimiter_from(&mut reader, None, PrintStyle::Continue, b';').unwrap();
+/// let second: u32 = read_until_delimiter_from(&mut reader, None, PrintStyle::Continue, b';').unwrap();
+/// let third: u32 = read_until_delimiter_from(&mut reader, None, PrintStyle::Continue, b';').unwrap();
+/// let end = read_until_delimiter_from::<_, u32>(&mut reader, None, PrintStyle::Continue, b';');
+///
+/// assert_eq!((first, second, third), (1, 22, 333));
+/// assert!(matches!(end, Err(InputError::Eof)));
+/// ```
+pub fn read_until_delimiter_from<R, T>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    delimiter: u8,
+) -> Result<T, InputError<T::Err>>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let mut record = Vec::new();
+    if reader.read_until(delimiter, &mut record)? == 0 {
+        return Err(InputError::Eof);
+    }
+    if record.last() == Some(&delimiter) {
+        record.pop();
+    }
+
+    let record = String::from_utf8(record).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    record.parse().map_err(InputError::Parse)
+}
+
+/// Reads one comma-separated line, following the quoting rules of RFC 4180:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line and splits it at every comma outside double quotes.
+/// 3. Unquotes `"quoted"` fields, turning `""` inside them into a single `"`.
+/// 4. Returns `Err(InputError::Parse(msg))` if a quote is left open at the end of
+///    the line, or a closing quote isn't followed by a comma.
+///
+/// Fields are returned as typed; surrounding whitespace is kept. A quoted field
+/// can't span lines.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_csv_row_from, InputError, PrintStyle};
+///
+/// let mut reader = Cursor::new("Alice,\"Smith, Jr.\",\"say \"\"hi\"\"\",\n\"open,quote\n");
+///
+/// let row = read_csv_row_from(&mut reader, None, PrintStyle::Continue).unwrap();
+/// assert_eq!(row, ["Alice", "Smith, Jr.", "say \"hi\"", ""]);
+///
+/// let unclosed = read_csv_row_from(&mut reader, None, PrintStyle::Continue);
+/// assert!(matches!(unclosed, Err(InputError::Parse(msg)) if msg == "unclosed quote in field 1"));
+/// ```
+pub fn read_csv_row_from<R: BufRead>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+) -> Result<Vec<String>, InputError<String>> {
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let input = read_line(reader, TrimStyle::TrailingNewline)?.ok_or(InputError::Eof)?;
+    split_csv_row(&input).map_err(InputError::Parse)
+}
+
+/// Reads one line and splits it into words the way a POSIX shell would:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line and splits it at unquoted whitespace.
+/// 3. Keeps `'single-quoted'` text literally, with no escapes inside.
+/// 4. Unescapes `\"` and `\\` inside `"double-quoted"` text; other backslashes stay as typed.
+/// 5. Outside quotes, a backslash escapes the next character.
+/// 6. Returns `Err(InputError::Parse(msg))` for an unclosed quote or a trailing backslash.
+///
+/// Quoted and bare parts next to each other form one word, so `--name="Jane Doe"`
+/// is a single word, and `""` is an empty word. Nothing is expanded: `$HOME`
+/// and `*` are returned as typed.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_shell_quoted_from, InputError, PrintStyle};
+///
+/// let mut reader = Cursor::new(concat!(
+///     r#"git commit -m "fix \"quoted\" paths" --author='Jane Doe' my\ file """#, "\n",
+///     r#"echo "unclosed"#, "\n",
+/// ));
+///
+/// let words = read_shell_quoted_from(&mut reader, None, PrintStyle::Continue).unwrap();
+/// assert_eq!(words, ["git", "commit", "-m", r#"fix "quoted" paths"#, "--author=Jane Doe", "my file", ""]);
+///
+/// let unclosed = read_shell_quoted_from(&mut reader, None, PrintStyle::Continue);
+/// assert!(matches!(unclosed, Err(InputError::Parse(msg)) if msg == "unclosed double quote"));
+/// ```
+pub fn read_shell_quoted_from<R: BufRead>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+) -> Result<Vec<String>, InputError<String>> {
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let input = read_line(reader, TrimStyle::TrailingNewline)?.ok_or(InputError::Eof)?;
+    split_shell_words(&input).map_err(InputError::Parse)
+}
+
+/// Reads one `key=value` or `key: value` style line:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line and splits it at the first character found in `separators`.
+/// 3. Trims both sides and returns them as a `(key, value)` pair.
+/// 4. Returns `Err(InputError::Parse(msg))` if the line has no separator.
+///
+/// Separators after the first one are part of the value.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_key_value_from, InputError, PrintStyle};
+///
+/// let mut reader = Cursor::new("name = Alice\nurl: http://example.com\nnothing here\n");
+///
+/// let (key, value) = read_key_value_from(&mut reader, None, PrintStyle::Continue, &['=', ':']).unwrap();
+/// assert_eq!((key.as_str(), value.as_str()), ("name", "Alice"));
+///
+/// let (key, value) = read_key_value_from(&mut reader, None, PrintStyle::Continue, &['=', ':']).unwrap();
+/// assert_eq!((key.as_str(), value.as_str()), ("url", "http://example.com"));
+///
+/// let missing = read_key_value_from(&mut reader, None, PrintStyle::Continue, &['=', ':']);
+/// assert!(matches!(missing, Err(InputError::Parse(_))));
+///
+/// let mut reader = Cursor::new("filter = a=b\n");
+/// let (key, value) = read_key_value_from(&mut reader, None, PrintStyle::Continue, &['=']).unwrap();
+/// assert_eq!((key.as_str(), value.as_str()), ("filter", "a=b"));
+/// ```
+pub fn read_key_value_from<R: BufRead>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    separators: &[char],
+) -> Result<(String, String), InputError<String>> {
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let input = read_line(reader, TrimStyle::TrailingNewline)?.ok_or(InputError::Eof)?;
+    split_key_value(&input, separators)
+}
+
+/// Reads [`read_key_value_from`] lines until EOF and collects them into a `HashMap`.
+///
+/// Later lines overwrite earlier ones with the same key. Any line without a
+/// separator fails the whole read with `Err(InputError::Parse(msg))`.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::read_key_value_map_from;
+///
+/// let mut reader = Cursor::new("host=localhost\nport=8080\nhost=example.com\n");
+/// let config = read_key_value_map_from(&mut reader, &['=']).unwrap();
+///
+/// assert_eq!(config.len(), 2);
+/// assert_eq!(config["host"], "example.com");
+/// assert_eq!(config["port"], "8080");
+/// ```
+pub fn read_key_value_map_from<R: BufRead>(
+    reader: &mut R,
+    separators: &[char],
+) -> Result<HashMap<String, String>, InputError<String>> {
+    let mut map = HashMap::new();
+    loop {
+        match read_key_value_from(reader, None, PrintStyle::Continue, separators) {
+            Ok((key, value)) => map.insert(key, value),
+            Err(InputError::Eof) => return Ok(map),
+            Err(e) => return Err(e),
+        };
+    }
+}
+
+/// Like [`read_key_value_map_from`], but stops at a blank line and keeps every pair in order:
+/// 1. Optionally prints a prompt once (and flushes).
+/// 2. Reads [`read_key_value_from`] lines until one is empty after trimming, or EOF is reached.
+/// 3. Returns the pairs in the order they were entered, duplicates included.
+/// 4. Returns `Err(InputError::Parse(msg))` as soon as a line has no separator.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::read_key_value_pairs_from;
+///
+/// let mut reader = Cursor::new("tag = a\ntag = b\n\nname = ignored\n");
+/// let pairs = read_key_value_pairs_from(&mut reader, None, &['=']).unwrap();
+///
+/// assert_eq!(pairs, [("tag".to_string(), "a".to_string()), ("tag".to_string(), "b".to_string())]);
+/// ```
+pub fn read_key_value_pairs_from<R: BufRead>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    separators: &[char],
+) -> Result<Vec<(String, String)>, InputError<String>> {
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, PrintStyle::Continue)?;
+    }
+
+    let mut pairs = Vec::new();
+    while let Some(line) = read_line(reader, TrimStyle::TrailingNewline)? {
+        if line.trim().is_empty() {
+            break;
+        }
+        pairs.push(split_key_value(&line, separators)?);
+    }
+    Ok(pairs)
+}
+
+/// Reads one line holding exactly two whitespace-separated values of different types:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line and splits it on whitespace.
+/// 3. Returns `Err(InputError::TokenCount { .. })` if there aren't exactly two tokens.
+/// 4. Parses the first token into `A` and the second into `B`, returning
+///    `Err(InputError::Parse(msg))` naming the token that failed.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_pair, InputError, PrintStyle};
+///
+/// let mut reader = Cursor::new("alice 30\nbob\n");
+///
+/// let (name, age): (String, u8) = read_pair(&mut reader, None, PrintStyle::Continue).unwrap();
+/// assert_eq!((name.as_str(), age), ("alice", 30));
+///
+/// let missing: Result<(String, u8), _> = read_pair(&mut reader, None, PrintStyle::Continue);
+/// assert!(matches!(missing, Err(InputError::TokenCount { expected: 2, found: 1 })));
+/// ```
+pub fn read_pair<R, A, B>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+) -> Result<(A, B), InputError<String>>
+where
+    R: BufRead,
+    A: FromStr,
+    A::Err: std::fmt::Display,
+    B: FromStr,
+    B::Err: std::fmt::Display,
+{
+    read_tuple_from(reader, prompt, print_style)
+}
+
+/// Like [`read_pair`], but for exactly three values of types `A`, `B` and `C`.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_triple, PrintStyle};
+///
+/// let mut reader = Cursor::new("Alice 30 1.75\n");
+///
+/// let record: (String, u8, f64) = read_triple(&mut reader, None, PrintStyle::Continue).unwrap();
+/// assert_eq!(record, ("Alice".to_string(), 30, 1.75));
+/// ```
+pub fn read_triple<R, A, B, C>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+) -> Result<(A, B, C), InputError<String>>
+where
+    R: BufRead,
+    A: FromStr,
+    A::Err: std::fmt::Display,
+    B: FromStr,
+    B::Err: std::fmt::Display,
+    C: FromStr,
+    C::Err: std::fmt::Display,
+{
+    read_tuple_from(reader, prompt, print_style)
+}
+
+/// Reads one line holding one whitespace-separated value per element of the tuple `T`:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line and splits it on whitespace.
+/// 3. Returns `Err(InputError::TokenCount { .. })` if the number of tokens doesn't
+///    match the number of tuple elements.
+/// 4. Parses each token into its element type, returning `Err(InputError::Parse(msg))`
+///    naming the token that failed.
+///
+/// Tuples of up to 8 elements are supported (see [`FromTokens`]).
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_tuple_from, InputError, PrintStyle};
+///
+/// let mut reader = Cursor::new("Alice 30 1.75 extra\nAlice x 1.75\n");
+///
+/// let too_many: Result<(String, u8, f64), _> = read_tuple_from(&mut reader, None, PrintStyle::Continue);
+/// assert!(matches!(too_many, Err(InputError::TokenCount { expected: 3, found: 4 })));
+///
+/// let invalid: Result<(String, u8, f64), _> = read_tuple_from(&mut reader, None, PrintStyle::Continue);
+/// assert!(matches!(invalid, Err(InputError::Parse(msg)) if msg.starts_with("value 2 ('x')")));
+/// ```
+pub fn read_tuple_from<R, T>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+) -> Result<T, InputError<String>>
+where
+    R: BufRead,
+    T: FromTokens,
+{
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let input = read_line(reader, TrimStyle::TrailingNewline)?.ok_or(InputError::Eof)?;
+    let tokens: Vec<&str> = input.split_whitespace().collect();
+    if tokens.len() != T::COUNT {
+        return Err(InputError::TokenCount { expected: T::COUNT, found: tokens.len() });
+    }
+    T::from_tokens(&tokens)
+}
+
+/// A tuple that can be parsed from a fixed number of tokens, one per element.
+///
+/// Implemented for tuples of up to 8 elements whose types implement `FromStr`.
+/// Used by [`read_tuple_from`] and the [`input_tuple!`] macro.
+pub trait FromTokens: Sized {
+    /// The number of tokens needed, which is the number of tuple elements.
+    const COUNT: usize;
+
+    /// Parses each token into the matching element.
+    /// `tokens` always holds exactly [`COUNT`](FromTokens::COUNT) tokens.
+    fn from_tokens(tokens: &[&str]) -> Result<Self, InputError<String>>;
+}
+
+macro_rules! impl_from_tokens {
+    ($count:expr; $($ty:ident $idx:tt),+) => {
+        impl<$($ty),+> FromTokens for ($($ty,)+)
+        where
+            $($ty: FromStr, $ty::Err: std::fmt::Display,)+
+        {
+            const COUNT: usize = $count;
+
+            fn from_tokens(tokens: &[&str]) -> Result<Self, InputError<String>> {
+                Ok(($(parse_token($idx + 1, tokens[$idx])?,)+))
+            }
+        }
+    };
+}
+
+impl_from_tokens!(1; A 0);
+impl_from_tokens!(2; A 0, B 1);
+impl_from_tokens!(3; A 0, B 1, C 2);
+impl_from_tokens!(4; A 0, B 1, C 2, D 3);
+impl_from_tokens!(5; A 0, B 1, C 2, D 3, E 4);
+impl_from_tokens!(6; A 0, B 1, C 2, D 3, E 4, F 5);
+impl_from_tokens!(7; A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_from_tokens!(8; A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// Presents a numbered menu and returns the zero-based index of the chosen item:
+/// 1. Prints the prompt and every item, numbered from 1, to `writer`.
+/// 2. Reads one line holding the 1-based number of the choice.
+/// 3. Returns `default` if the line is empty and a default was given.
+/// 4. On an empty, unparsable or out-of-range answer, prints an error and shows the menu again.
+/// 5. Returns `Err(InputError::Eof)` if EOF is reached, or `Err(InputError::Io)` on I/O failure.
+///
+/// # Panics
+///
+/// Panics if `default` is not a valid index into `items`.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{select_from, InputError};
+///
+/// let colors = ["Red", "Green", "Blue"];
+///
+/// // "7" is out of range, so the menu is shown again before "3" is accepted.
+/// let mut output = Vec::new();
+/// let index = select_from(&mut Cursor::new("7\n3\n"), &mut output, format_args!("Pick one:"), &colors, None);
+/// assert_eq!(index.unwrap(), 2);
+/// assert!(String::from_utf8(output).unwrap().starts_with("Pick one:\n  1) Red\n  2) Green\n  3) Blue\nChoice: "));
+///
+/// // An empty line picks the default.
+/// let index = select_from(&mut Cursor::new("\n"), &mut Vec::new(), format_args!("Pick one:"), &colors, Some(1));
+/// assert_eq!(index.unwrap(), 1);
+///
+/// // Without a default, an empty line is rejected until input runs out.
+/// let index = select_from(&mut Cursor::new("\n"), &mut Vec::new(), format_args!("Pick one:"), &colors, None);
+/// assert!(matches!(index, Err(InputError::Eof)));
+/// ```
+pub fn select_from<R, W, T>(
+    reader: &mut R,
+    writer: &mut W,
+    prompt: Arguments<'_>,
+    items: &[T],
+    default: Option<usize>,
+) -> Result<usize, InputError<String>>
+where
+    R: BufRead,
+    W: Write,
+    T: std::fmt::Display,
+{
+    if let Some(default) = default {
+        assert!(default < items.len(), "default index {} is out of range", default);
+    }
+
+    retry_with(writer, usize::MAX, |writer| read_menu_choice(reader, writer, prompt, items, default))
+}
+
+/// Like [`select_from`], but makes a single attempt and has no default:
+/// 1. Prints the prompt and every item, numbered from 1, to `writer`.
+/// 2. Reads one line holding the 1-based number of the choice.
+/// 3. Returns the zero-based index of the chosen item.
+/// 4. Returns `Err(InputError::Empty)` for an empty line, and `Err(InputError::Parse(msg))`
+///    if the answer isn't a number in range.
+/// 5. Returns `Err(InputError::Eof)` if EOF is reached, or `Err(InputError::Io)` on I/O failure.
+///
+/// Wrap the call in [`retry_with`] to show the menu again after an invalid answer.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_select_from, retry_with, InputError};
+///
+/// let colors = ["Red", "Green", "Blue"];
+///
+/// let mut output = Vec::new();
+/// let index = read_select_from(&mut Cursor::new("2\n"), &mut output, &colors, format_args!("Pick one:"));
+/// assert_eq!(index.unwrap(), 1);
+/// assert_eq!(String::from_utf8(output).unwrap(), "Pick one:\n  1) Red\n  2) Green\n  3) Blue\nChoice: ");
+///
+/// let index = read_select_from(&mut Cursor::new("0\n"), &mut Vec::new(), &colors, format_args!("Pick one:"));
+/// assert_eq!(index.unwrap_err(), InputError::Parse("please enter a number between 1 and 3".to_string()));
+///
+/// // Up to three attempts.
+/// let mut reader = Cursor::new("4\nblue\n3\n");
+/// let index = retry_with(&mut Vec::new(), 3, |writer| {
+///     read_select_from(&mut reader, writer, &colors, format_args!("Pick one:"))
+/// });
+/// assert_eq!(index.unwrap(), 2);
+/// ```
+pub fn read_select_from<R, W, T>(
+    reader: &mut R,
+    writer: &mut W,
+    items: &[T],
+    prompt: Arguments<'_>,
+) -> Result<usize, InputError<String>>
+where
+    R: BufRead,
+    W: Write,
+    T: std::fmt::Display,
+{
+    read_menu_choice(reader, writer, prompt, items, None)
+}
+
+/// Shows the menu once and reads one answer, for [`select_from`] and [`read_select_from`].
+fn read_menu_choice<R, W, T>(
+    reader: &mut R,
+    writer: &mut W,
+    prompt: Arguments<'_>,
+    items: &[T],
+    default: Option<usize>,
+) -> Result<usize, InputError<String>>
+where
+    R: BufRead,
+    W: Write,
+    T: std::fmt::Display,
+{
+    writeln!(writer, "{}", prompt)?;
+    for (number, item) in (1..).zip(items) {
+        writeln!(writer, "  {}) {}", number, item)?;
+    }
+    match default {
+        Some(default) => write!(writer, "Choice [{}]: ", default + 1)?,
+        None => write!(writer, "Choice: ")?,
+    }
+    writer.flush()?;
+
+    let input = read_line(reader, TrimStyle::Trim)?.ok_or(InputError::Eof)?;
+    match (input.is_empty(), default) {
+        (true, Some(default)) => return Ok(default),
+        (true, None) => return Err(InputError::Empty),
+        _ => {}
+    }
+    match input.parse::<usize>() {
+        Ok(number) if (1..=items.len()).contains(&number) => Ok(number - 1),
+        _ => Err(InputError::Parse(format!(
+            "please enter a number between 1 and {}",
+            items.len()
+        ))),
+    }
+}
+
+/// Like [`read_until_blank_line`], but joins the block into one `String`:
+/// 1. Optionally prints a prompt once (and flushes).
+/// 2. Collects lines until one is empty after trimming, or EOF is reached.
+/// 3. Returns them joined with `\n`, without the terminating blank line.
+/// 4. Returns `Err(InputError::Eof)` if EOF is reached before any line was read,
+///    and `Err(InputError::Io)` on I/O failure.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_block_from, InputError};
+///
+/// let mut reader = Cursor::new("Dear Bob,\r\n  see you soon.\n\nP.S. bring snacks\n");
+///
+/// let letter = read_block_from(&mut reader, None).unwrap();
+/// assert_eq!(letter, "Dear Bob,\n  see you soon.");
+///
+/// let postscript = read_block_from(&mut reader, None).unwrap();
+/// assert_eq!(postscript, "P.S. bring snacks");
+///
+/// assert!(matches!(read_block_from(&mut reader, None), Err(InputError::Eof)));
+/// ```
+pub fn read_block_from<R: BufRead>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+) -> Result<String, InputError<Infallible>> {
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, PrintStyle::Continue)?;
+    }
+
+    let mut lines = Vec::new();
+    loop {
+        match read_line(reader, TrimStyle::TrailingNewline)? {
+            Some(line) if !line.trim().is_empty() => lines.push(line),
+            Some(_) => break,
+            None if lines.is_empty() => return Err(InputError::Eof),
+            None => break,
+        }
+    }
+    Ok(lines.join("\n"))
+}
+
+/// Reads every remaining line from the provided `BufRead` until EOF.
+///
+/// Only the line terminators (`\n` or `\r\n`) are removed; any other
+/// whitespace is kept so the caller can decide how to handle it.
+/// Returns `Err(e)` on the first I/O failure.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::read_all_lines_from;
+///
+/// let mut reader = Cursor::new("first\n  second  \r\nthird");
+/// let lines = read_all_lines_from(&mut reader).unwrap();
+///
+/// assert_eq!(lines, ["first", "  second  ", "third"]);
+/// ```
+pub fn read_all_lines_from<R: BufRead>(reader: &mut R) -> Result<Vec<String>, io::Error> {
+    reader.by_ref().lines().collect()
+}
+
+/// Reads every remaining line until EOF and parses each one into `T`:
+/// 1. Trims surrounding whitespace from each line and parses it.
+/// 2. Returns all values in order once EOF is reached.
+/// 3. Stops at the first line that fails to parse, returning
+///    `Err(InputError::Parse(msg))` with its 1-based line number, like `"line 3: ..."`.
+///
+/// Counting starts at the reader's current position. See [`parsed_lines`] to
+/// handle each line's result separately instead of failing fast.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_lines_collect, InputError};
+///
+/// let numbers = read_lines_collect::<_, i32>(&mut Cursor::new("3\n-1\r\n 4 \n")).unwrap();
+/// assert_eq!(numbers, [3, -1, 4]);
+///
+/// let bad = read_lines_collect::<_, i32>(&mut Cursor::new("1\n2\nthree\n4\n"));
+/// assert_eq!(bad.unwrap_err(), InputError::Parse("line 3: invalid digit found in string".to_string()));
+/// ```
+pub fn read_lines_collect<R, T>(reader: &mut R) -> Result<Vec<T>, InputError<String>>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    let mut values = Vec::new();
+    let mut line_number = 0;
+    while let Some(line) = read_line(reader, TrimStyle::Trim)? {
+        line_number += 1;
+        let value = line
+            .parse::<T>()
+            .map_err(|e| InputError::Parse(format!("line {}: {}", line_number, e)))?;
+        values.push(value);
+    }
+    Ok(values)
+}
+
+/// Reads one line from the provided `BufRead` as raw bytes.
+///
+/// Nothing is trimmed or decoded: the result includes the trailing `\n`
+/// (or `\r\n`) if there was one, and may contain invalid UTF-8. See
+/// [`read_line_raw_string`] for the same read decoded as UTF-8.
+/// Returns `Err(InputError::Eof)` if no bytes were read, and
+/// `Err(InputError::Io)` on I/O failure.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_line_raw, InputError};
+///
+/// let mut reader = Cursor::new(b"\x1b[A\xff\r\nlast".to_vec());
+///
+/// assert_eq!(read_line_raw(&mut reader).unwrap(), b"\x1b[A\xff\r\n");
+/// assert_eq!(read_line_raw(&mut reader).unwrap(), b"last");
+/// assert!(matches!(read_line_raw(&mut reader), Err(InputError::Eof)));
+/// ```
+pub fn read_line_raw<R: BufRead>(reader: &mut R) -> Result<Vec<u8>, InputError<Infallible>> {
+    let mut buffer = Vec::new();
+    if reader.read_until(b'\n', &mut buffer)? == 0 {
+        return Err(InputError::Eof);
+    }
+    Ok(buffer)
+}
+
+/// Like [`read_line_raw`], but strips the line terminator (`\n` or `\r\n`).
+///
+/// The remaining bytes are returned untouched, without any UTF-8 validation.
+/// Returns `Err(InputError::Eof)` if no bytes were read, and
+/// `Err(InputError::Io)` on I/O failure.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_bytes_line, InputError};
+///
+/// let mut reader = Cursor::new(b"\xff\xfe ok\r\n\x00\x80\n\n".to_vec());
+///
+/// assert_eq!(read_bytes_line(&mut reader).unwrap(), b"\xff\xfe ok");
+/// assert_eq!(read_bytes_line(&mut reader).unwrap(), b"\x00\x80");
+/// assert_eq!(read_bytes_line(&mut reader).unwrap(), b"");
+/// assert!(matches!(read_bytes_line(&mut reader), Err(InputError::Eof)));
+/// ```
+pub fn read_bytes_line<R: BufRead>(reader: &mut R) -> Result<Vec<u8>, InputError<Infallible>> {
+    let mut line = read_line_raw(reader)?;
+    if line.last() == Some(&b'\n') {
+        line.pop();
+        if line.last() == Some(&b'\r') {
+            line.pop();
+        }
+    }
+    Ok(line)
+}
+
+/// Like [`read_line_raw`], but returns the line as a `String`.
+///
+/// The line terminator (`\n` or `\r\n`) is kept, so the line can be forwarded
+/// verbatim. Returns `Err(InputError::Eof)` if no bytes were read, and
+/// `Err(InputError::Io)` on I/O failure or invalid UTF-8.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_line_raw_string, InputError};
+///
+/// let mut reader = Cursor::new("unix\nwindows\r\nlast");
+///
+/// assert_eq!(read_line_raw_string(&mut reader).unwrap(), "unix\n");
+/// assert_eq!(read_line_raw_string(&mut reader).unwrap(), "windows\r\n");
+/// assert_eq!(read_line_raw_string(&mut reader).unwrap(), "last");
+/// assert!(matches!(read_line_raw_string(&mut reader), Err(InputError::Eof)));
+/// ```
+pub fn read_line_raw_string<R: BufRead>(reader: &mut R) -> Result<String, InputError<Infallible>> {
+    let mut line = String::new();
+    if reader.read_line(&mut line)? == 0 {
+        return Err(InputError::Eof);
+    }
+    Ok(line)
+}
+
+/// Reads the next whitespace-separated word from the provided `BufRead`:
+/// 1. Skips leading whitespace, including line breaks.
+/// 2. Collects characters until the next whitespace or EOF.
+/// 3. Returns `Err(InputError::Eof)` if EOF is reached before a word starts.
+/// 4. Returns `Err(InputError::Io)` on I/O failure or invalid UTF-8.
+///
+/// Unlike the line-based readers, nothing after the word is consumed, so the
+/// rest of the line stays buffered in `reader` for the next call.
+///
+/// # Usage:
+/// ```
+/// use std::io::{BufRead, Cursor};
+/// use input_lib::{read_word_from, InputError};
+///
+/// let mut reader = Cursor::new("  3 7\n\n 12 ");
+///
+/// assert_eq!(read_word_from(&mut reader).unwrap(), "3");
+/// assert_eq!(read_word_from(&mut reader).unwrap(), "7");
+/// assert_eq!(read_word_from(&mut reader).unwrap(), "12");
+/// assert!(matches!(read_word_from(&mut reader), Err(InputError::Eof)));
+///
+/// // The separator after a word is left in the reader.
+/// let mut reader = Cursor::new("cmd rest of line\n");
+/// assert_eq!(read_word_from(&mut reader).unwrap(), "cmd");
+/// let mut rest = String::new();
+/// reader.read_line(&mut rest).unwrap();
+/// assert_eq!(rest, " rest of line\n");
+/// ```
+pub fn read_word_from<R: BufRead>(reader: &mut R) -> Result<String, InputError<Infallible>> {
+    loop {
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
+            return Err(InputError::Eof);
+        }
+        let skipped = buffer.iter().take_while(|byte| byte.is_ascii_whitespace()).count();
+        let word_found = skipped < buffer.len();
+        reader.consume(skipped);
+        if word_found {
+            break;
+        }
+    }
+
+    let mut word = Vec::new();
+    loop {
+        let buffer = reader.fill_buf()?;
+        let len = buffer.iter().take_while(|byte| !byte.is_ascii_whitespace()).count();
+        word.extend_from_slice(&buffer[..len]);
+        let word_ended = len < buffer.len() || buffer.is_empty();
+        reader.consume(len);
+        if word_ended {
+            break;
+        }
+    }
+
+    String::from_utf8(word)
+        .map_err(|e| InputError::Io(io::Error::new(io::ErrorKind::InvalidData, e)))
+}
+
+/// Reads one line from the provided `BufRead`, accepting `\n`, `\r\n` and a lone `\r`
+/// as line endings:
+/// 1. Collects bytes up to the first `\r` or `\n`.
+/// 2. Consumes the line ending, including the `\n` of a `\r\n` pair.
+/// 3. Returns the line without its ending.
+/// 4. Returns `Err(InputError::Eof)` if EOF is reached before any byte, and
+///    `Err(InputError::Io)` on I/O failure or invalid UTF-8.
+///
+/// The other line readers only split on `\n`, so a file saved with classic Mac
+/// `\r` endings reads as one long line. To tell a lone `\r` from `\r\n`, the
+/// byte after a `\r` is looked at, which may wait for more input.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_line_any_ending, InputError};
+///
+/// for text in ["one\ntwo\n", "one\r\ntwo\r\n", "one\rtwo\r", "one\rtwo"] {
+///     let mut reader = Cursor::new(text);
+///     assert_eq!(read_line_any_ending(&mut reader).unwrap(), "one");
+///     assert_eq!(read_line_any_ending(&mut reader).unwrap(), "two");
+///     assert!(matches!(read_line_any_ending(&mut reader), Err(InputError::Eof)));
+/// }
+///
+/// // An empty line between two `\r` endings is kept.
+/// let mut reader = Cursor::new("a\r\rb\r");
+/// assert_eq!(read_line_any_ending(&mut reader).unwrap(), "a");
+/// assert_eq!(read_line_any_ending(&mut reader).unwrap(), "");
+/// assert_eq!(read_line_any_ending(&mut reader).unwrap(), "b");
+/// ```
+pub fn read_line_any_ending<R: BufRead>(reader: &mut R) -> Result<String, InputError<Infallible>> {
+    let mut line = Vec::new();
+    let mut read_any = false;
+    loop {
+        let buffer = reader.fill_buf()?;
+        if buffer.is_empty() {
+            if !read_any {
+                return Err(InputError::Eof);
+            }
+            break;
+        }
+        read_any = true;
+
+        let Some(end) = buffer.iter().position(|&byte| byte == b'\r' || byte == b'\n') else {
+            let len = buffer.len();
+            line.extend_from_slice(buffer);
+            reader.consume(len);
+            continue;
+        };
+        let ending = buffer[end];
+        line.extend_from_slice(&buffer[..end]);
+        reader.consume(end + 1);
+        if ending == b'\r' && reader.fill_buf()?.first() == Some(&b'\n') {
+            reader.consume(1);
+        }
+        break;
+    }
+
+    String::from_utf8(line)
+        .map_err(|e| InputError::Io(io::Error::new(io::ErrorKind::InvalidData, e)))
+}
+
+/// Reads exactly `n` lines from the provided `BufRead`.
+///
+/// Only the line terminators are removed; any other whitespace is kept.
+/// Returns `Err(InputError::Eof)` if EOF is reached before `n` lines were
+/// read, and `Err(InputError::Io)` on I/O failure.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_n_lines, InputError};
+///
+/// let mut reader = Cursor::new("3 4\n1 2\n");
+/// let lines = read_n_lines(&mut reader, 2).unwrap();
+/// assert_eq!(lines, ["3 4", "1 2"]);
+///
+/// let mut reader = Cursor::new("only one\n");
+/// assert!(matches!(read_n_lines(&mut reader, 2), Err(InputError::Eof)));
+/// ```
+pub fn read_n_lines<R: BufRead>(
+    reader: &mut R,
+    n: usize,
+) -> Result<Vec<String>, InputError<Infallible>> {
+    let mut lines = Vec::new();
+    for _ in 0..n {
+        lines.push(read_line(reader, TrimStyle::TrailingNewline)?.ok_or(InputError::Eof)?);
+    }
+    Ok(lines)
+}
+
+/// Reads everything left in the provided `Read` and parses it as one value:
+/// 1. Reads until EOF with `read_to_string`.
+/// 2. Returns `Err(InputError::Eof)` if the stream was already empty.
+/// 3. Parses the whole text into `T`, returning `Err(InputError::Parse)` on failure.
+/// 4. Returns `Err(InputError::Io)` on I/O failure or invalid UTF-8.
+///
+/// Nothing is trimmed, so a `String` keeps every line break.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_all_from, InputError};
+///
+/// let mut reader = Cursor::new("first\nsecond\r\nthird\n");
+/// let document: String = read_all_from(&mut reader).unwrap();
+/// assert_eq!(document, "first\nsecond\r\nthird\n");
+///
+/// let empty: Result<String, _> = read_all_from(&mut reader);
+/// assert!(matches!(empty, Err(InputError::Eof)));
+/// ```
+pub fn read_all_from<R, T>(reader: &mut R) -> Result<T, InputError<T::Err>>
+where
+    R: Read,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    let mut input = String::new();
+    if reader.read_to_string(&mut input)? == 0 {
+        return Err(InputError::Eof);
+    }
+    input.parse::<T>().map_err(InputError::Parse)
+}
+
+/// Parses an in-memory string the same way the readers parse a line:
+/// 1. Trims surrounding whitespace.
+/// 2. Parses into type `T`, returning `Err(InputError::Parse)` on failure.
+///
+/// Useful for values that come from elsewhere (arguments, environment
+/// variables, config files) but should share the `InputError` error path.
+///
+/// # Usage:
+/// ```
+/// use input_lib::read_from_str;
+///
+/// let port: u16 = read_from_str(" 8080\n").unwrap();
+/// assert_eq!(port, 8080);
+///
+/// assert!(read_from_str::<u16>("http").unwrap_err().is_parse());
+/// ```
+pub fn read_from_str<T>(s: &str) -> Result<T, InputError<T::Err>>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    s.trim().parse::<T>().map_err(InputError::Parse)
+}
+
+/// Parses a string exactly like [`read_input_from`] with `TrimStyle::TrailingNewline`
+/// parses a line, minus the I/O:
+/// 1. Strips the line terminator (`\n` or `\r\n`).
+/// 2. Parses into type `T`, returning `Err(InputError::Parse)` on failure.
+///
+/// Other whitespace is kept, so interactive and non-interactive paths (like a
+/// GUI text field) accept the same inputs. See [`read_from_str`] to trim all
+/// surrounding whitespace instead.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{parse_trimmed, read_input_from, PrintStyle, TrimStyle};
+///
+/// for text in ["42\r\n", "42 \n", " 42"] {
+///     let parsed = parse_trimmed::<i32>(text).ok();
+///     let read = read_input_from::<_, i32>(
+///         &mut Cursor::new(text),
+///         None,
+///         PrintStyle::Continue,
+///         TrimStyle::TrailingNewline,
+///     ).ok();
+///     assert_eq!(parsed, read);
+/// }
+///
+/// assert_eq!(parse_trimmed::<i32>("42\r\n").unwrap(), 42);
+/// assert!(parse_trimmed::<i32>("42 ").unwrap_err().is_parse());
+/// assert_eq!(parse_trimmed::<String>(" padded \n").unwrap(), " padded ");
+/// ```
+pub fn parse_trimmed<T>(s: &str) -> Result<T, InputError<T::Err>>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    TrimStyle::TrailingNewline.apply(s).parse::<T>().map_err(InputError::Parse)
+}
+
+/// Reads a block of lines terminated by a blank line:
+/// 1. Optionally prints a prompt once (and flushes).
+/// 2. Collects lines until one is empty after trimming, or EOF is reached.
+/// 3. Returns `Err(e)` on the first I/O failure.
+///
+/// The terminating blank line is not included. Only the line terminators are
+/// removed from the collected lines, so indentation is preserved.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::read_until_blank_line;
+///
+/// let mut reader = Cursor::new("Fix the parser\n\n  - handle tabs\n");
+/// let subject = read_until_blank_line(&mut reader, None).unwrap();
+/// let body = read_until_blank_line(&mut reader, None).unwrap();
+///
+/// assert_eq!(subject, ["Fix the parser"]);
+/// assert_eq!(body, ["  - handle tabs"]);
+/// ```
+pub fn read_until_blank_line<R: BufRead>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+) -> Result<Vec<String>, io::Error> {
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, PrintStyle::Continue)?;
+    }
+
+    let mut lines = Vec::new();
+    while let Some(line) = read_line(reader, TrimStyle::TrailingNewline)? {
+        if line.trim().is_empty() {
+            break;
+        }
+        lines.push(line);
+    }
+    Ok(lines)
+}
+
+/// Reads an indented block, as in Python or YAML:
+/// 1. Optionally prints a prompt once (and flushes).
+/// 2. Collects lines as long as each starts with at least `indent_spaces` spaces.
+/// 3. Strips exactly `indent_spaces` leading spaces and the line terminator from each.
+/// 4. Stops at EOF or the first line with less indentation, which is left in `reader`.
+/// 5. Returns `Err(e)` on the first I/O failure.
+///
+/// The indentation of each line is checked with [`PeekReader::peek`], so it is
+/// seen in full however little the wrapped reader buffers, and nothing is
+/// consumed until the line is known to belong to the block.
+///
+/// # Usage:
+/// ```
+/// use std::io::{BufRead, BufReader, Cursor};
+/// use input_lib::{read_indented_block_from, PeekReader};
+///
+/// let mut reader = PeekReader::new(Cursor::new("    x = 1\n      nested\n    y = 2\n  outer\n"));
+///
+/// let block = read_indented_block_from(&mut reader, None, 4).unwrap();
+/// assert_eq!(block, ["x = 1", "  nested", "y = 2"]);
+///
+/// // The less indented line is still there for the next read.
+/// let mut next = String::new();
+/// reader.read_line(&mut next).unwrap();
+/// assert_eq!(next, "  outer\n");
+///
+/// // A wrapped reader with a tiny buffer gives the same result.
+/// let mut reader = PeekReader::new(BufReader::with_capacity(2, Cursor::new("    ab\n    cd\n  outer\n")));
+/// let block = read_indented_block_from(&mut reader, None, 4).unwrap();
+/// assert_eq!(block, ["ab", "cd"]);
+///
+/// let mut next = String::new();
+/// reader.read_line(&mut next).unwrap();
+/// assert_eq!(next, "  outer\n");
+/// ```
+pub fn read_indented_block_from<R: BufRead>(
+    reader: &mut PeekReader<R>,
+    prompt: Option<Arguments<'_>>,
+    indent_spaces: usize,
+) -> Result<Vec<String>, io::Error> {
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, PrintStyle::Continue)?;
+    }
+
+    let mut lines = Vec::new();
+    loop {
+        // Peek at least one byte, so EOF is noticed even when no indentation is required.
+        let ahead = reader.peek(indent_spaces.max(1))?;
+        let indented = ahead.len() >= indent_spaces && ahead[..indent_spaces].iter().all(|&byte| byte == b' ');
+        if ahead.is_empty() || !indented {
+            return Ok(lines);
+        }
+
+        reader.consume(indent_spaces);
+        lines.push(read_line(reader, TrimStyle::TrailingNewline)?.unwrap_or_default());
+    }
+}
+
+/// A `BufRead` adapter that can look further ahead than the wrapped reader's buffer.
+///
+/// [`PeekReader::peek`] moves bytes out of the wrapped reader into a small
+/// buffer of its own, where the next read still finds them. That makes it
+/// possible to inspect the start of a line and leave it unread, even when
+/// the wrapped reader only delivers a few bytes at a time.
+///
+/// # Usage:
+/// ```
+/// use std::io::{BufRead, BufReader, Cursor};
+/// use input_lib::PeekReader;
+///
+/// let mut reader = PeekReader::new(BufReader::with_capacity(1, Cursor::new("hello\n")));
+/// assert_eq!(reader.peek(3).unwrap(), b"hel");
+/// assert_eq!(reader.peek(10).unwrap(), b"hello\n");
+///
+/// let mut line = String::new();
+/// reader.read_line(&mut line).unwrap();
+/// assert_eq!(line, "hello\n");
+/// assert_eq!(reader.peek(3).unwrap(), b"");
+/// ```
+#[derive(Debug)]
+pub struct PeekReader<R> {
+    reader: R,
+    peeked: Vec<u8>,
+    pos: usize,
+}
+
+impl<R: BufRead> PeekReader<R> {
+    /// Wraps `reader`.
+    pub fn new(reader: R) -> Self {
+        PeekReader { reader, peeked: Vec::new(), pos: 0 }
+    }
+
+    /// Returns the next `n` bytes without consuming them, or fewer if EOF comes first.
+    pub fn peek(&mut self, n: usize) -> io::Result<&[u8]> {
+        if self.pos == self.peeked.len() {
+            self.peeked.clear();
+            self.pos = 0;
+        }
+        while self.peeked.len() - self.pos < n {
+            let buffer = self.reader.fill_buf()?;
+            if buffer.is_empty() {
+                break;
+            }
+            let len = (n - (self.peeked.len() - self.pos)).min(buffer.len());
+            self.peeked.extend_from_slice(&buffer[..len]);
+            self.reader.consume(len);
+        }
+        let end = self.peeked.len().min(self.pos + n);
+        Ok(&self.peeked[self.pos..end])
+    }
+}
+
+impl<R: BufRead> Read for PeekReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let available = self.fill_buf()?;
+        let len = available.len().min(buf.len());
+        buf[..len].copy_from_slice(&available[..len]);
+        self.consume(len);
+        Ok(len)
+    }
+}
+
+impl<R: BufRead> BufRead for PeekReader<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if self.pos < self.peeked.len() {
+            return Ok(&self.peeked[self.pos..]);
+        }
+        self.reader.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        if self.pos < self.peeked.len() {
+            self.pos = self.peeked.len().min(self.pos + amt);
+        } else {
+            self.reader.consume(amt)
+        }
+    }
+}
+
+/// Like [`read_until_blank_line`], but stops at a sentinel line such as `.` or `END`:
+/// 1. Optionally prints a prompt once (and flushes).
+/// 2. Collects lines until one equals `sentinel` after trimming, or EOF is reached.
+/// 3. Returns them joined with `\n`, without the sentinel line or a trailing newline.
+/// 4. Returns `Err(e)` on the first I/O failure.
+///
+/// Blank lines are kept, so the block may contain paragraphs.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::read_multiline_until_sentinel;
+///
+/// let mut reader = Cursor::new("Hello,\n\n  world\n .\nP.S.\n");
+///
+/// let body = read_multiline_until_sentinel(&mut reader, None, ".").unwrap();
+/// assert_eq!(body, "Hello,\n\n  world");
+///
+/// // EOF ends the block too.
+/// let rest = read_multiline_until_sentinel(&mut reader, None, ".").unwrap();
+/// assert_eq!(rest, "P.S.");
+/// ```
+pub fn read_multiline_until_sentinel<R: BufRead>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    sentinel: &str,
+) -> Result<String, io::Error> {
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, PrintStyle::Continue)?;
+    }
+
+    let mut lines = Vec::new();
+    while let Some(line) = read_line(reader, TrimStyle::TrailingNewline)? {
+        if line.trim() == sentinel {
+            break;
+        }
+        lines.push(line);
+    }
+    Ok(lines.join("\n"))
+}
+
+/// Like [`read_until_blank_line`], but parses each line and stops when `predicate` fails:
+/// 1. Optionally prints a prompt once (and flushes).
+/// 2. Reads lines, trims them and parses each into `T`.
+/// 3. Collects values as long as `predicate` returns `true`.
+/// 4. Stops at the first value for which it returns `false`, or at EOF, and
+///    returns the values collected so far.
+/// 5. Returns `Err(InputError::Parse)` or `Err(InputError::Io)` as soon as one occurs.
+///
+/// The value that ends the loop is not included. Its line has been read,
+/// though, so it is gone from `reader` too.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_while, PrintStyle};
+///
+/// // Read scores until a negative one marks the end.
+/// let mut reader = Cursor::new("10\n 7 \n-1\n42\n");
+/// let scores: Vec<i32> = read_while(&mut reader, None, PrintStyle::Continue, |&n| n >= 0).unwrap();
+/// assert_eq!(scores, [10, 7]);
+///
+/// // EOF also ends the loop.
+/// let rest: Vec<i32> = read_while(&mut reader, None, PrintStyle::Continue, |&n| n >= 0).unwrap();
+/// assert_eq!(rest, [42]);
+/// ```
+pub fn read_while<R, T, F>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    predicate: F,
+) -> Result<Vec<T>, InputError<T::Err>>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+    F: Fn(&T) -> bool,
+{
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let mut values = Vec::new();
+    while let Some(line) = read_line(reader, TrimStyle::Trim)? {
+        let value = line.parse::<T>().map_err(InputError::Parse)?;
+        if !predicate(&value) {
+            break;
+        }
+        values.push(value);
+    }
+    Ok(values)
+}
+
+/// Like [`read_input_from`], but checks the parsed value against a predicate:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line and parses it into `T`, returning `Err(InputError::Parse)` on failure.
+/// 3. Returns `Err(InputError::Validation(msg))` if `predicate` returns `false`.
+///
+/// This covers range checks and other domain rules that `FromStr` can't express.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_validated_from, InputError, PrintStyle};
+///
+/// let mut reader = Cursor::new("42\n200\n");
+///
+/// let age = read_validated_from(&mut reader, None, PrintStyle::Continue, |n: &u8| *n <= 120);
+/// assert_eq!(age.unwrap(), 42);
+///
+/// let age = read_validated_from(&mut reader, None, PrintStyle::Continue, |n: &u8| *n <= 120);
+/// assert!(matches!(age, Err(InputError::Validation(_))));
+/// ```
+pub fn read_validated_from<R, T, F>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    predicate: F,
+) -> Result<T, InputError<T::Err>>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+    F: FnOnce(&T) -> bool,
+{
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let input = read_line(reader, TrimStyle::Trim)?.ok_or(InputError::Eof)?;
+    let value = input.parse::<T>().map_err(InputError::Parse)?;
+    if !predicate(&value) {
+        return Err(InputError::Validation(format!("'{}' is not an accepted value", input)));
+    }
+    Ok(value)
+}
+
+/// Like [`read_input_from`], but parses an integer written in the given `radix`:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line and trims surrounding whitespace.
+/// 3. Parses it with `from_str_radix`, returning `Err(InputError::Parse)` on failure.
+///
+/// # Panics
+///
+/// Panics if `radix` is not in the range `2..=36`, like `from_str_radix`.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_input_radix, PrintStyle};
+///
+/// let mut reader = Cursor::new("1010\n755\nff\n");
+///
+/// let binary: u8 = read_input_radix(&mut reader, None, PrintStyle::Continue, 2).unwrap();
+/// let octal: u32 = read_input_radix(&mut reader, None, PrintStyle::Continue, 8).unwrap();
+/// let hex: i64 = read_input_radix(&mut reader, None, PrintStyle::Continue, 16).unwrap();
+///
+/// assert_eq!((binary, octal, hex), (10, 0o755, 255));
+/// ```
+pub fn read_input_radix<R, T>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    radix: u32,
+) -> Result<T, InputError<ParseIntError>>
+where
+    R: BufRead,
+    T: FromStrRadix,
+{
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let input = read_line(reader, TrimStyle::Trim)?.ok_or(InputError::Eof)?;
+    T::from_str_radix(&input, radix).map_err(InputError::Parse)
+}
+
+/// An integer type that can be parsed from a string in any radix from 2 to 36.
+///
+/// Implemented for every primitive integer type. Used by [`read_input_radix`].
+pub trait FromStrRadix: Sized {
+    /// Parses `src` as a number in base `radix`, like `i64::from_str_radix`.
+    fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError>;
+}
+
+macro_rules! impl_from_str_radix {
+    ($($ty:ty),+) => {
+        $(
+            impl FromStrRadix for $ty {
+                fn from_str_radix(src: &str, radix: u32) -> Result<Self, ParseIntError> {
+                    <$ty>::from_str_radix(src, radix)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_str_radix!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Like [`read_input_from`], but accepts numbers written with a locale's separators:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line and trims surrounding whitespace.
+/// 3. Removes every `locale.grouping` character and turns `locale.decimal` into `.`.
+/// 4. Parses the result into `T`, returning `Err(InputError::Parse)` on failure.
+///
+/// This is meant for numeric types; other reads should keep using [`read_input_from`].
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_number_locale, NumberLocale, PrintStyle};
+///
+/// let mut reader = Cursor::new("1,234.56\n1.234,56\n1 234,56\n");
+///
+/// let us: f64 = read_number_locale(&mut reader, None, PrintStyle::Continue, NumberLocale::US).unwrap();
+/// let eu: f64 = read_number_locale(&mut reader, None, PrintStyle::Continue, NumberLocale::EUROPEAN).unwrap();
+/// let fr: f64 = read_number_locale(&mut reader, None, PrintStyle::Continue, NumberLocale::new(' ', ',')).unwrap();
+///
+/// assert_eq!((us, eu, fr), (1234.56, 1234.56, 1234.56));
+///
+/// let mut reader = Cursor::new("1.234\n");
+/// let count: u32 = read_number_locale(&mut reader, None, PrintStyle::Continue, NumberLocale::EUROPEAN).unwrap();
+/// assert_eq!(count, 1234);
+/// ```
+pub fn read_number_locale<R, T>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    locale: NumberLocale,
+) -> Result<T, InputError<T::Err>>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let input = read_line(reader, TrimStyle::Trim)?.ok_or(InputError::Eof)?;
+    locale.normalize(&input).parse::<T>().map_err(InputError::Parse)
+}
+
+/// The digit grouping and decimal separators used by [`read_number_locale`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberLocale {
+    /// Separates groups of digits, like the `,` in `1,234`. Removed before parsing.
+    pub grouping: char,
+    /// Separates the integer and fractional parts, like the `.` in `0.5`.
+    pub decimal: char,
+}
+
+impl NumberLocale {
+    /// `1,234.56`
+    pub const US: NumberLocale = NumberLocale::new(',', '.');
+    /// `1.234,56`
+    pub const EUROPEAN: NumberLocale = NumberLocale::new('.', ',');
+
+    /// Creates a locale with the given grouping and decimal separators.
+    pub const fn new(grouping: char, decimal: char) -> Self {
+        NumberLocale { grouping, decimal }
+    }
+
+    /// Rewrites `input` with the separators Rust's number parsing expects.
+    fn normalize(self, input: &str) -> String {
+        input
+            .chars()
+            .filter(|&c| c != self.grouping)
+            .map(|c| if c == self.decimal { '.' } else { c })
+            .collect()
+    }
+}
+
+impl Default for NumberLocale {
+    fn default() -> Self {
+        NumberLocale::US
+    }
+}
+
+/// Reads a line that must contain more than whitespace:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line and trims surrounding whitespace.
+/// 3. Returns `Err(InputError::Parse("input must not be empty"))` if nothing is left.
+/// 4. Otherwise returns the trimmed line.
+///
+/// Wrap the call in [`retry_with`] to keep asking until the user types something.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_nonempty_string_from, retry_with, PrintStyle};
+///
+/// let mut reader = Cursor::new("   \n  alice \n");
+/// let mut output = Vec::new();
+///
+/// let name = retry_with(&mut output, usize::MAX, |_| {
+///     read_nonempty_string_from(&mut reader, None, PrintStyle::Continue)
+/// }).unwrap();
+///
+/// assert_eq!(name, "alice");
+/// assert_eq!(String::from_utf8(output).unwrap(), "input must not be empty\n");
+/// ```
+pub fn read_nonempty_string_from<R: BufRead>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+) -> Result<String, InputError<String>> {
+    read_nonempty_from(reader, prompt, print_style).map_err(|e| match e {
+        InputError::Empty => InputError::Parse("input must not be empty".into()),
+        e => e.map_parse(|never: Infallible| match never {}),
+    })
+}
+
+/// Reads a line whose length must be within `min_len..=max_len`:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line and trims surrounding whitespace.
+/// 3. Returns `Err(InputError::Validation(msg))` if the trimmed line is shorter
+///    than `min_len` or longer than `max_len`.
+/// 4. Otherwise returns the trimmed line.
+///
+/// Lengths are counted in `char`s (Unicode scalar values), not bytes, so `"año"`
+/// has length 3. Wrap the call in [`retry_with`] to keep asking until the length fits.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_string_bounded, InputError, PrintStyle};
+///
+/// let mut reader = Cursor::new("ab\n  abc \nabcdefgh\nabcdefghi\nañoñoño\n");
+/// let mut read = || read_string_bounded(&mut reader, None, PrintStyle::Continue, 3, 8);
+///
+/// assert_eq!(
+///     read().unwrap_err(),
+///     InputError::Validation("must be between 3 and 8 characters long, got 2".to_string()),
+/// );
+/// assert_eq!(read().unwrap(), "abc");
+/// assert_eq!(read().unwrap(), "abcdefgh");
+/// assert!(matches!(read(), Err(InputError::Validation(_))));
+///
+/// // 7 characters, even though it takes 10 bytes.
+/// assert_eq!(read().unwrap(), "añoñoño");
+/// ```
+pub fn read_string_bounded<R: BufRead>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    min_len: usize,
+    max_len: usize,
+) -> Result<String, InputError<Infallible>> {
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let input = read_line(reader, TrimStyle::Trim)?.ok_or(InputError::Eof)?;
+    let len = input.chars().count();
+    if !(min_len..=max_len).contains(&len) {
+        return Err(InputError::Validation(format!(
+            "must be between {} and {} characters long, got {}",
+            min_len, max_len, len
+        )));
+    }
+    Ok(input)
+}
+
+/// Like [`read_validated_from`], but reports a rejected value with a custom message:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line and parses it into `T`.
+/// 3. Returns `Err(InputError::Parse(msg))` with the parse error's message if parsing fails.
+/// 4. Returns `Err(InputError::Parse(err_msg))` if `validate` returns `false`.
+///
+/// Both failures share the `String` error type, which only needs `T::Err` to
+/// implement `Display`. Use [`read_validated_from`] to keep `T`'s native parse
+/// error instead. Wrap the call in [`retry_with`] to re-prompt on invalid values.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_with_validation, retry_with, PrintStyle};
+///
+/// let mut reader = Cursor::new("-5\nabc\n12.5\n");
+/// let mut output = Vec::new();
+///
+/// let price = retry_with(&mut output, 3, |_| {
+///     read_with_validation(&mut reader, None, PrintStyle::Continue, |p: &f64| *p >= 0.0, "price can't be negative")
+/// }).unwrap();
+///
+/// assert_eq!(price, 12.5);
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "price can't be negative\ninvalid float literal\n",
+/// );
+/// ```
+pub fn read_with_validation<R, T, F>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    validate: F,
+    err_msg: &str,
+) -> Result<T, InputError<String>>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: std::fmt::Display,
+    F: Fn(&T) -> bool,
+{
+    let value = read_parsed_to_string(reader, prompt, print_style)?;
+    if !validate(&value) {
+        return Err(InputError::Parse(err_msg.to_string()));
+    }
+    Ok(value)
+}
+
+/// Reads a value within `range`:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line, trims it and parses it into `T`, returning `Err(InputError::Parse)` on failure.
+/// 3. Returns `Err(InputError::OutOfRange { value, bounds })` if `range` doesn't contain the value.
+///
+/// Any range works: `a..b`, `a..=b`, `a..`, `..b`, `..=b` and `..`.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_in_range, InputError, PrintStyle};
+///
+/// let mut reader = Cursor::new("10\n0\n1000000\n");
+///
+/// let top = read_in_range(&mut reader, None, PrintStyle::Continue, 1..=10);
+/// assert_eq!(top.unwrap(), 10);
+///
+/// let below = read_in_range(&mut reader, None, PrintStyle::Continue, 1..=10);
+/// assert_eq!(
+///     below.unwrap_err(),
+///     InputError::OutOfRange { value: "0".to_string(), bounds: "1..=10".to_string() },
+/// );
+///
+/// let open_ended = read_in_range(&mut reader, None, PrintStyle::Continue, 1u32..);
+/// assert_eq!(open_ended.unwrap(), 1_000_000);
+/// ```
+pub fn read_in_range<R, T, B>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    range: B,
+) -> Result<T, InputError<T::Err>>
+where
+    R: BufRead,
+    T: FromStr + PartialOrd + std::fmt::Display,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+    B: RangeBounds<T>,
+{
+    let value: T = read_input_from(reader, prompt, print_style, TrimStyle::Trim)?;
+    if !range.contains(&value) {
+        return Err(InputError::OutOfRange { value: value.to_string(), bounds: describe_range(&range) });
+    }
+    Ok(value)
+}
+
+/// Reads a number within the inclusive range `min..=max`:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line and parses it into `T`, returning `Err(InputError::Parse(msg))` on failure.
+/// 3. Returns `Err(InputError::Parse(msg))` like `"value must be between 1 and 100"`
+///    unless `min <= value && value <= max`.
+///
+/// Works for any `PartialOrd + FromStr` type. See [`read_bounded_number_exclusive`]
+/// for exclusive bounds.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_bounded_number, InputError, PrintStyle};
+///
+/// let mut reader = Cursor::new("100\n101\n");
+///
+/// let n = read_bounded_number(&mut reader, None, PrintStyle::Continue, 1, 100);
+/// assert_eq!(n.unwrap(), 100);
+///
+/// let n = read_bounded_number(&mut reader, None, PrintStyle::Continue, 1, 100);
+/// assert!(matches!(n, Err(InputError::Parse(msg)) if msg == "value must be between 1 and 100"));
+/// ```
+pub fn read_bounded_number<R, T>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    min: T,
+    max: T,
+) -> Result<T, InputError<String>>
+where
+    R: BufRead,
+    T: FromStr + PartialOrd + std::fmt::Display,
+    T::Err: std::fmt::Display,
+{
+    let value = read_parsed_to_string(reader, prompt, print_style)?;
+    if !(min <= value && value <= max) {
+        return Err(InputError::Parse(format!("value must be between {} and {}", min, max)));
+    }
+    Ok(value)
+}
+
+/// Like [`read_bounded_number`], but with exclusive bounds: requires `min < value && value < max`.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_bounded_number_exclusive, InputError, PrintStyle};
+///
+/// let mut reader = Cursor::new("0.5\n1.0\n");
+///
+/// let ratio = read_bounded_number_exclusive(&mut reader, None, PrintStyle::Continue, 0.0, 1.0);
+/// assert_eq!(ratio.unwrap(), 0.5);
+///
+/// let ratio = read_bounded_number_exclusive(&mut reader, None, PrintStyle::Continue, 0.0, 1.0);
+/// assert!(matches!(ratio, Err(InputError::Parse(msg)) if msg == "value must be strictly between 0 and 1"));
+/// ```
+pub fn read_bounded_number_exclusive<R, T>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    min: T,
+    max: T,
+) -> Result<T, InputError<String>>
+where
+    R: BufRead,
+    T: FromStr + PartialOrd + std::fmt::Display,
+    T::Err: std::fmt::Display,
+{
+    let value = read_parsed_to_string(reader, prompt, print_style)?;
+    if !(min < value && value < max) {
+        return Err(InputError::Parse(format!("value must be strictly between {} and {}", min, max)));
+    }
+    Ok(value)
+}
+
+/// Reads a value twice and only accepts it if both entries match, as in
+/// "enter password, confirm password":
+/// 1. Prints `first_prompt` to `writer` and reads one line.
+/// 2. Prints `second_prompt` to `writer` and reads another line.
+/// 3. Returns `Err(InputError::Mismatch)` if the two lines differ.
+/// 4. Parses the line once into `T`, returning `Err(InputError::Parse(msg))` on failure.
+///
+/// The lines are compared before parsing, so mistyped entries are reported as
+/// a mismatch even when they happen to parse to the same value. Wrap the call
+/// in [`retry_with`] to start over after a mismatch.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_confirmed, InputError, PrintStyle};
+///
+/// let mut reader = Cursor::new("hunter2\nhunter2\n42\n042\n");
+/// let mut output = Vec::new();
+///
+/// let password: String = read_confirmed(
+///     &mut reader,
+///     &mut output,
+///     Some(format_args!("Password: ")),
+///     Some(format_args!("Confirm: ")),
+///     PrintStyle::Continue,
+/// ).unwrap();
+/// assert_eq!(password, "hunter2");
+/// assert_eq!(output, b"Password: Confirm: ");
+///
+/// let pin: Result<u32, _> = read_confirmed(&mut reader, &mut Vec::new(), None, None, PrintStyle::Continue);
+/// assert_eq!(pin.unwrap_err(), InputError::Mismatch);
+/// ```
+pub fn read_confirmed<R, W, T>(
+    reader: &mut R,
+    writer: &mut W,
+    first_prompt: Option<Arguments<'_>>,
+    second_prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+) -> Result<T, InputError<String>>
+where
+    R: BufRead,
+    W: Write,
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    let mut entries = Vec::with_capacity(2);
+    for prompt in [first_prompt, second_prompt] {
+        if let Some(prompt_args) = prompt {
+            write_prompt(writer, prompt_args, print_style)?;
+        }
+        entries.push(read_line(reader, TrimStyle::TrailingNewline)?.ok_or(InputError::Eof)?);
+    }
+
+    if entries[0] != entries[1] {
+        return Err(InputError::Mismatch);
+    }
+    entries[0].parse::<T>().map_err(|e| InputError::Parse(e.to_string()))
+}
+
+/// Like [`read_input_from`], but keeps asking until the input parses:
+/// 1. Prints the prompt to `writer` (and flushes).
+/// 2. Reads one line from the provided `BufRead` and tries to parse it.
+/// 3. On a parse failure, writes the error message to `writer` and starts over.
+/// 4. After `max_attempts` failed parses, returns the last `Err(InputError::Parse)`.
+///
+/// `Eof` and `Io` errors are returned immediately, without retrying.
+/// At least one attempt is always made, even if `max_attempts` is `0`.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::read_with_retry;
+///
+/// let mut reader = Cursor::new("abc\n42\n");
+/// let mut output = Vec::new();
+///
+/// let number: i32 = read_with_retry(
+///     &mut reader,
+///     &mut output,
+///     Some(format_args!("Enter a number: ")),
+///     3,
+/// ).unwrap();
+///
+/// assert_eq!(number, 42);
+/// assert_eq!(
+///     String::from_utf8(output).unwrap(),
+///     "Enter a number: invalid digit found in string\nEnter a number: ",
+/// );
+///
+/// // Gives up after two failed attempts, even though a valid line follows.
+/// let mut reader = Cursor::new("x\ny\n7\n");
+/// let result: Result<i32, _> = read_with_retry(&mut reader, &mut Vec::new(), None, 2);
+/// assert!(result.unwrap_err().is_parse());
+/// ```
+pub fn read_with_retry<R, W, T>(
+    reader: &mut R,
+    writer: &mut W,
+    prompt: Option<Arguments<'_>>,
+    max_attempts: usize,
+) -> Result<T, InputError<T::Err>>
+where
+    R: BufRead,
+    W: Write,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    retry_with(writer, max_attempts, |writer| {
+        read_input_from_with_writer(reader, writer, prompt, PrintStyle::Continue, TrimStyle::Trim)
+    })
+}
+
+/// Like [`read_with_retry`], but also returns how many attempts were used:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line from the provided `BufRead` and tries to parse it.
+/// 3. On a parse failure, prints the error message and starts over.
+/// 4. Returns the parsed value together with the number of attempts, counting the successful one.
+/// 5. After `max_attempts` failed parses, returns the last `Err(InputError::Parse)`.
+///
+/// Error messages go where the prompt goes: stderr for the `PrintStyle::Stderr*`
+/// styles, stdout otherwise. `Eof` and `Io` errors are returned immediately.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_with_attempts, PrintStyle};
+///
+/// let mut reader = Cursor::new("abc\n4.5\n42\n");
+///
+/// let (number, attempts): (i32, _) = read_with_attempts(&mut reader, None, PrintStyle::Continue, 5).unwrap();
+///
+/// assert_eq!(number, 42);
+/// assert_eq!(attempts, 3);
+/// ```
+pub fn read_with_attempts<R, T>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    max_attempts: usize,
+) -> Result<(T, usize), InputError<T::Err>>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: std::fmt::Display + std::fmt::Debug,
+{
+    let mut attempts = 0;
+    let mut attempt = || {
+        attempts += 1;
+        read_input_from(reader, prompt, print_style, TrimStyle::Trim)
+    };
+    let value = match print_style {
+        PrintStyle::Stderr | PrintStyle::StderrNewLine => retry_with(&mut io::stderr(), max_attempts, |_| attempt()),
+        _ => retry_with(&mut io::stdout(), max_attempts, |_| attempt()),
+    }?;
+    Ok((value, attempts))
+}
+
+/// Runs `attempt` until it succeeds, re-trying on invalid input:
+/// 1. Calls `attempt`, handing it `writer` for printing prompts.
+/// 2. On `Parse`, `Empty`, `Validation`, `OutOfRange` or `Mismatch` errors, writes the
+///    error message to `writer` and tries again.
+/// 3. After `max_attempts` failed attempts, returns the last error.
+///
+/// `Eof`, `Io` and `Timeout` errors are returned immediately, without retrying.
+/// At least one attempt is always made, even if `max_attempts` is `0`.
+///
+/// This is what [`read_with_retry`] is built on; use it to add retries to
+/// any other read, such as [`read_one_of`].
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{retry_with, read_one_of, PrintStyle};
+///
+/// let mut reader = Cursor::new("purple\ngreen\n");
+/// let mut output = Vec::new();
+///
+/// let color = retry_with(&mut output, 3, |_| {
+///     read_one_of(&mut reader, None, PrintStyle::Continue, &["red", "green", "blue"].map(String::from))
+/// }).unwrap();
+///
+/// assert_eq!(color, "green");
+/// assert_eq!(String::from_utf8(output).unwrap(), "'purple' is not one of the accepted values\n");
+/// ```
+pub fn retry_with<W, T, E, F>(
+    writer: &mut W,
+    max_attempts: usize,
+    mut attempt: F,
+) -> Result<T, InputError<E>>
+where
+    W: Write,
+    E: std::fmt::Display + std::fmt::Debug,
+    F: FnMut(&mut W) -> Result<T, InputError<E>>,
+{
+    let mut attempts = 0;
+    loop {
+        attempts += 1;
+        match attempt(writer) {
+            Err(InputError::Parse(e)) if attempts < max_attempts => writeln!(writer, "{}", e)?,
+            Err(InputError::Validation(msg)) if attempts < max_attempts => writeln!(writer, "{}", msg)?,
+            Err(e @ (InputError::Empty | InputError::OutOfRange { .. } | InputError::Mismatch))
+                if attempts < max_attempts =>
+            {
+                writeln!(writer, "{}", e)?
+            }
+            result => return result,
+        }
+    }
+}
+
+/// Prints the optional prompt, then reads and parses one line,
+/// turning a parse failure into its `String` message.
+fn read_parsed_to_string<R, T>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+) -> Result<T, InputError<String>>
+where
+    R: BufRead,
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let input = read_line(reader, TrimStyle::Trim)?.ok_or(InputError::Eof)?;
+    input.parse::<T>().map_err(|e| InputError::Parse(e.to_string()))
+}
+
+/// Parses the token at 1-based `position`, describing which token failed on error.
+fn parse_token<T>(position: usize, token: &str) -> Result<T, InputError<String>>
+where
+    T: FromStr,
+    T::Err: std::fmt::Display,
+{
+    token
+        .parse::<T>()
+        .map_err(|e| InputError::Parse(format!("value {} ('{}'): {}", position, token, e)))
+}
+
+/// Splits one CSV line into its fields, describing the first quoting error found.
+fn split_csv_row(line: &str) -> Result<Vec<String>, String> {
+    let mut fields = Vec::new();
+    let mut chars = line.chars().peekable();
+    loop {
+        let mut field = String::new();
+        if chars.next_if_eq(&'"').is_some() {
+            loop {
+                match chars.next() {
+                    Some('"') if chars.next_if_eq(&'"').is_some() => field.push('"'),
+                    Some('"') => break,
+                    Some(c) => field.push(c),
+                    None => return Err(format!("unclosed quote in field {}", fields.len() + 1)),
+                }
+            }
+            if !matches!(chars.peek(), Some(',') | None) {
+                return Err(format!("unexpected text after closing quote in field {}", fields.len() + 1));
+            }
+        } else {
+            while let Some(c) = chars.next_if(|&c| c != ',') {
+                field.push(c);
+            }
+        }
+        fields.push(field);
+        if chars.next().is_none() {
+            return Ok(fields);
+        }
+    }
+}
+
+/// Splits `line` into shell words, describing the first quoting error found.
+fn split_shell_words(line: &str) -> Result<Vec<String>, String> {
+    let mut words = Vec::new();
+    let mut word = String::new();
+    // Tracked separately from `word.is_empty()`, so `""` still yields a word.
+    let mut in_word = false;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut word));
+                    in_word = false;
+                }
+                continue;
+            }
+            '\'' => loop {
+                match chars.next() {
+                    Some('\'') => break,
+                    Some(c) => word.push(c),
+                    None => return Err("unclosed single quote".to_string()),
+                }
+            },
+            '"' => loop {
+                match chars.next() {
+                    Some('"') => break,
+                    Some('\\') => match chars.next() {
+                        Some(c @ ('"' | '\\')) => word.push(c),
+                        Some(c) => {
+                            word.push('\\');
+                            word.push(c);
+                        }
+                        None => return Err("unclosed double quote".to_string()),
+                    },
+                    Some(c) => word.push(c),
+                    None => return Err("unclosed double quote".to_string()),
+                }
+            },
+            '\\' => word.push(chars.next().ok_or("trailing backslash")?),
+            c => word.push(c),
+        }
+        in_word = true;
+    }
+    if in_word {
+        words.push(word);
+    }
+    Ok(words)
+}
+
+/// Splits `line` at the first of `separators` into a trimmed `(key, value)` pair.
+fn split_key_value(line: &str, separators: &[char]) -> Result<(String, String), InputError<String>> {
+    let (key, value) = line
+        .split_once(separators)
+        .ok_or_else(|| InputError::Parse("no separator found in input".to_string()))?;
+    Ok((key.trim().to_string(), value.trim().to_string()))
+}
+
+/// Formats `range` the way it would be written in Rust, like `1..=10` or `..5`.
+fn describe_range<T: std::fmt::Display>(range: &impl RangeBounds<T>) -> String {
+    let start = match range.start_bound() {
+        Bound::Included(start) | Bound::Excluded(start) => start.to_string(),
+        Bound::Unbounded => String::new(),
+    };
+    let end = match range.end_bound() {
+        Bound::Included(end) => format!("={}", end),
+        Bound::Excluded(end) => end.to_string(),
+        Bound::Unbounded => String::new(),
+    };
+    format!("{}..{}", start, end)
+}
+
+/// Parses the yes/no spellings accepted by [`read_boolean_from`], ignoring case.
+fn parse_yes_no(answer: &str) -> Option<bool> {
+    match answer.to_ascii_lowercase().as_str() {
+        "yes" | "y" | "true" | "1" => Some(true),
+        "no" | "n" | "false" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+/// Prints the prompt to stdout, or to stderr for the `PrintStyle::Stderr*` styles.
+fn print_prompt(prompt: Arguments<'_>, print_style: PrintStyle) -> io::Result<()> {
+    match print_style {
+        PrintStyle::Stderr | PrintStyle::StderrNewLine => write_prompt(&mut io::stderr(), prompt, print_style),
+        _ => write_prompt(&mut io::stdout(), prompt, print_style),
+    }
+}
+
+/// Writes the prompt using the given style and flushes, so the user sees it immediately.
+/// `PrintStyle::NoFlush` leaves flushing to the caller, and `PrintStyle::Silent` writes nothing.
+fn write_prompt<W: Write>(writer: &mut W, prompt: Arguments<'_>, print_style: PrintStyle) -> io::Result<()> {
+    match print_style {
+        PrintStyle::Continue | PrintStyle::Stderr => write!(writer, "{}", prompt)?,
+        PrintStyle::NewLine | PrintStyle::StderrNewLine => writeln!(writer, "{}", prompt)?,
+        PrintStyle::NoFlush => return write!(writer, "{}", prompt),
+        PrintStyle::Silent => return Ok(()),
+    }
+    writer.flush()
+}
+
+/// Runs `read` on a background thread and waits at most `timeout` for its line.
+/// On timeout the thread is detached, still blocked in `read`.
+fn read_line_on_thread<E, F>(read: F, timeout: Duration) -> Result<String, InputError<E>>
+where
+    F: FnOnce() -> io::Result<Option<String>> + Send + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        // The receiver is gone if we already timed out, so there's nobody to tell.
+        let _ = sender.send(read());
+    });
+
+    match receiver.recv_timeout(timeout) {
+        Ok(line) => line?.ok_or(InputError::Eof),
+        Err(RecvTimeoutError::Timeout) => Err(InputError::Timeout(timeout)),
+        Err(RecvTimeoutError::Disconnected) => Err(InputError::Io(io::Error::other("input thread panicked"))),
+    }
+}
+
+/// Reads one line and trims it according to `trim_style`.
+/// Returns `Ok(None)` on EOF (read_line returned 0).
+fn read_line<R: InputSource>(reader: &mut R, trim_style: TrimStyle) -> io::Result<Option<String>> {
+    let mut input = String::new();
+    if InputSource::read_line(reader, &mut input)? == 0 {
+        return Ok(None);
+    }
+
+    Ok(Some(trim_style.apply(&input).to_string()))
+}
+
+/// A source of input lines for [`read_input_from`] and [`read_input_from_with_writer`].
+///
+/// Every `BufRead` is an `InputSource`, so stdin, files and `Cursor`s work as-is.
+/// Implement it directly for sources that only deal in whole lines, such as
+/// [`MockSource`](crate::testing::MockSource).
+///
+/// # Usage:
+/// ```
+/// use std::io;
+/// use input_lib::{read_input_from, InputSource, PrintStyle, TrimStyle};
+///
+/// /// Answers every read with the same line.
+/// struct Always(&'static str);
+///
+/// impl InputSource for Always {
+///     fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+///         buf.push_str(self.0);
+///         Ok(self.0.len())
+///     }
+/// }
+///
+/// let mut source = Always("7\n");
+/// let n: u8 = read_input_from(&mut source, None, PrintStyle::Continue, TrimStyle::Trim).unwrap();
+/// assert_eq!(n, 7);
+/// ```
+pub trait InputSource {
+    /// Appends the next line, including its line terminator, to `buf`.
+    ///
+    /// Returns the number of bytes appended, with `0` meaning EOF,
+    /// just like [`BufRead::read_line`].
+    fn read_line(&mut self, buf: &mut String) -> io::Result<usize>;
+}
+
+impl<R: BufRead + ?Sized> InputSource for R {
+    fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+        BufRead::read_line(self, buf)
+    }
+}
+
+/// An [`InputSource`] that passes on the lines of `source`, leaving out those
+/// that start with `prefix` after leading whitespace, and blank ones if
+/// `skip_blank` is set.
+struct SkipComments<'a, S: ?Sized> {
+    source: &'a mut S,
+    prefix: &'a str,
+    skip_blank: bool,
+}
+
+impl<S: InputSource + ?Sized> InputSource for SkipComments<'_, S> {
+    fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+        loop {
+            let mut line = String::new();
+            let read = self.source.read_line(&mut line)?;
+            let content = line.trim_start();
+            let comment = !self.prefix.is_empty() && content.starts_with(self.prefix);
+            if read == 0 || !(comment || (self.skip_blank && content.is_empty())) {
+                buf.push_str(&line);
+                return Ok(read);
+            }
+        }
+    }
+}
+
+/// A builder for one-off reads with several options.
+///
+/// Unlike [`InputReader`], the builder doesn't own a reader or writer; it only
+/// holds the options. They default to the behavior of the [`input!`] macro: no
+/// prompt, `PrintStyle::Continue`, `TrimStyle::Trim`, a single attempt and no
+/// default value. The builder calls [`read_input_from`] to do the actual read.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{InputBuilder, PrintStyle, TrimStyle};
+///
+/// let builder = InputBuilder::new()
+///     .prompt("Port: ")
+///     .print_style(PrintStyle::NewLine)
+///     .trim(TrimStyle::Trim)
+///     .default_value(8080);
+///
+/// let given: u16 = builder.read_from(&mut Cursor::new("  3000  \n")).unwrap();
+/// let empty: u16 = builder.read_from(&mut Cursor::new("   \n")).unwrap();
+/// let eof: u16 = builder.read_from(&mut Cursor::new("")).unwrap();
+///
+/// assert_eq!((given, empty, eof), (3000, 8080, 8080));
+///
+/// // Without a default, EOF and blank lines behave like `read_input_from`.
+/// let builder = InputBuilder::default().trim(TrimStyle::TrailingNewline);
+/// let raw: String = builder.read_from(&mut Cursor::new(" keep \n")).unwrap();
+/// assert_eq!(raw, " keep ");
+/// assert!(builder.read_from::<_, String>(&mut Cursor::new("")).unwrap_err().is_eof());
+///
+/// // With retries, invalid answers print their error and ask again.
+/// let builder = InputBuilder::new().prompt(format_args!("Age (try {}): ", 3)).retry(3);
+/// let age: u8 = builder.read_from(&mut Cursor::new("old\n-1\n 30 \n")).unwrap();
+/// assert_eq!(age, 30);
+/// assert!(builder.read_from::<_, u8>(&mut Cursor::new("a\nb\nc\n4\n")).unwrap_err().is_parse());
+/// ```
+#[derive(Debug, Clone)]
+pub struct InputBuilder {
+    prompt: Option<String>,
+    print_style: PrintStyle,
+    trim_style: TrimStyle,
+    max_attempts: usize,
+    default_value: Option<String>,
+}
+
+impl InputBuilder {
+    /// Creates a builder with the same defaults as the [`input!`] macro.
+    pub fn new() -> Self {
+        InputBuilder {
+            prompt: None,
+            print_style: PrintStyle::Continue,
+            trim_style: TrimStyle::Trim,
+            max_attempts: 1,
+            default_value: None,
+        }
+    }
+
+    /// Sets the prompt printed before reading.
+    pub fn prompt(mut self, prompt: impl std::fmt::Display) -> Self {
+        self.prompt = Some(prompt.to_string());
+        self
+    }
+
+    /// Sets how the prompt is printed.
+    pub fn print_style(mut self, print_style: PrintStyle) -> Self {
+        self.print_style = print_style;
+        self
+    }
+
+    /// Sets how much whitespace is removed from the line before parsing.
+    pub fn trim(mut self, trim_style: TrimStyle) -> Self {
+        self.trim_style = trim_style;
+        self
+    }
+
+    /// Allows up to `max_attempts` reads (see [`retry_with`]). Each failed attempt
+    /// prints its error where the prompt goes, then asks again.
+    pub fn retry(mut self, max_attempts: usize) -> Self {
+        self.max_attempts = max_attempts;
+        self
+    }
+
+    /// Sets the value used in place of an empty (or whitespace-only) line or EOF.
+    ///
+    /// The value is kept as its `Display` text and parsed like typed input,
+    /// so a default that doesn't parse into the type being read surfaces as
+    /// `Err(InputError::Parse)`.
+    pub fn default_value(mut self, default: impl std::fmt::Display) -> Self {
+        self.default_value = Some(default.to_string());
+        self
+    }
+
+    /// Reads and parses one line from stdin using the configured options.
+    pub fn read<T>(&self) -> Result<T, InputError<T::Err>>
+    where
+        T: FromStr,
+        T::Err: std::fmt::Display + std::fmt::Debug,
+    {
+        self.read_from(&mut io::stdin().lock())
+    }
+
+    /// Reads and parses one line from `reader` using the configured options.
+    pub fn read_from<R, T>(&self, reader: &mut R) -> Result<T, InputError<T::Err>>
+    where
+        R: BufRead,
+        T: FromStr,
+        T::Err: std::fmt::Display + std::fmt::Debug,
+    {
+        match self.print_style {
+            PrintStyle::Stderr | PrintStyle::StderrNewLine => {
+                retry_with(&mut io::stderr(), self.max_attempts, |_| self.read_once(reader))
+            }
+            _ => retry_with(&mut io::stdout(), self.max_attempts, |_| self.read_once(reader)),
+        }
+    }
+
+    /// A single attempt of [`InputBuilder::read_from`], applying the default.
+    fn read_once<R, T>(&self, reader: &mut R) -> Result<T, InputError<T::Err>>
+    where
+        R: BufRead,
+        T: FromStr,
+        T::Err: std::fmt::Display + std::fmt::Debug,
+    {
+        let Some(default) = &self.default_value else {
+            return self.read_line_from(reader);
+        };
+
+        let line = match self.read_line_from::<R, String>(reader) {
+            Err(InputError::Eof) => return default.parse().map_err(InputError::Parse),
+            result => result.map_err(|e| e.map_parse(|never| match never {}))?,
+        };
+        let line = if line.trim().is_empty() { default } else { &line };
+        line.parse().map_err(InputError::Parse)
+    }
+
+    /// Calls [`read_input_from`] with the configured prompt and styles.
+    fn read_line_from<R, T>(&self, reader: &mut R) -> Result<T, InputError<T::Err>>
+    where
+        R: BufRead,
+        T: FromStr,
+        T::Err: std::fmt::Display + std::fmt::Debug,
+    {
+        match &self.prompt {
+            Some(prompt) => read_input_from(
+                reader,
+                Some(format_args!("{}", prompt)),
+                self.print_style,
+                self.trim_style,
+            ),
+            None => read_input_from(reader, None, self.print_style, self.trim_style),
+        }
+    }
+}
+
+impl Default for InputBuilder {
+    fn default() -> Self {
+        InputBuilder::new()
+    }
+}
+
+/// A reader that pairs an input source with the destination for its prompts.
+///
+/// Where [`read_input_from`] always prints prompts to stdout, `InputReader`
+/// writes them to any `Write` target, so prompts can go to stderr, a file,
+/// or a buffer in tests.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{InputReader, PrintStyle};
+///
+/// let mut input = InputReader::new(Cursor::new("Alice\n30\n"), Vec::new());
+///
+/// let name: String = input.read("Name: ", PrintStyle::Continue).unwrap();
+/// let age: u8 = input.read("Age: ", PrintStyle::Continue).unwrap();
+///
+/// assert_eq!((name.as_str(), age), ("Alice", 30));
+/// ```
+#[derive(Debug)]
+pub struct InputReader<R, W> {
+    reader: R,
+    writer: W,
+    auto_prompt: bool,
+    comment_prefix: Option<String>,
+}
+
+impl<R: BufRead, W: Write> InputReader<R, W> {
+    /// Creates a reader that reads lines from `reader` and writes prompts to `writer`.
+    pub fn new(reader: R, writer: W) -> Self {
+        InputReader { reader, writer, auto_prompt: false, comment_prefix: None }
+    }
+
+    /// When enabled, prompts are skipped if stdin is not a terminal (see [`is_stdin_tty`]),
+    /// so piping input in doesn't mix prompts into the output. Off by default.
+    ///
+    /// This checks the process's stdin, whatever the reader is.
+    ///
+    /// # Usage:
+    /// ```no_run
+    /// use input_lib::{InputReader, PrintStyle};
+    ///
+    /// let mut input = InputReader::stdin().auto_prompt(true);
+    /// let name: String = input.read("Name: ", PrintStyle::Continue).unwrap();
+    /// ```
+    pub fn auto_prompt(mut self, enabled: bool) -> Self {
+        self.auto_prompt = enabled;
+        self
+    }
+
+    /// Makes [`InputReader::read`] skip lines starting with `prefix` after leading
+    /// whitespace, like [`read_skip_comments_from`]. Off by default.
+    ///
+    /// # Usage:
+    /// ```
+    /// use std::io::Cursor;
+    /// use input_lib::{InputReader, PrintStyle};
+    ///
+    /// let script = "# name\nAlice\n# age\n30\n";
+    /// let mut input = InputReader::new(Cursor::new(script), Vec::new()).skip_comments("#");
+    ///
+    /// let name: String = input.read("Name: ", PrintStyle::Continue).unwrap();
+    /// let age: u8 = input.read("Age: ", PrintStyle::Continue).unwrap();
+    ///
+    /// assert_eq!((name.as_str(), age), ("Alice", 30));
+    /// ```
+    pub fn skip_comments(mut self, prefix: impl Into<String>) -> Self {
+        self.comment_prefix = Some(prefix.into());
+        self
+    }
+
+    /// A single method that:
+    /// 1. Prints the prompt to the writer (and flushes), unless skipped by [`InputReader::auto_prompt`].
+    /// 2. Reads one line from the reader, skipping comments if [`InputReader::skip_comments`] is set.
+    /// 3. Returns `Err(InputError::Eof)` if EOF is reached.
+    /// 4. Parses into type `T`, returning `Err(InputError::Parse)` on failure.
+    /// 5. Returns `Err(InputError::Io)` on I/O failure.
+    pub fn read<T>(
+        &mut self,
+        prompt: impl std::fmt::Display,
+        print_style: PrintStyle,
+    ) -> Result<T, InputError<T::Err>>
+    where
+        T: FromStr,
+        T::Err: std::fmt::Display + std::fmt::Debug,
+    {
+        let show_prompt = !self.auto_prompt || is_stdin_tty();
+        let prefix = self.comment_prefix.as_deref().unwrap_or("");
+        read_input_from_with_writer(
+            &mut SkipComments { source: &mut self.reader, prefix, skip_blank: false },
+            &mut self.writer,
+            show_prompt.then_some(format_args!("{}", prompt)),
+            print_style,
+            TrimStyle::Trim,
+        )
+    }
+
+    /// Returns an iterator over the remaining lines of the reader.
+    ///
+    /// No prompt is printed; see [`InputLines`].
+    pub fn lines(&mut self) -> InputLines<&mut R> {
+        lines_from(&mut self.reader)
+    }
+}
+
+impl InputReader<io::StdinLock<'static>, io::Stdout> {
+    /// Reads from stdin and prints prompts to stdout, like the [`input!`] macro.
+    pub fn stdin() -> Self {
+        InputReader::new(io::stdin().lock(), io::stdout())
+    }
+}
+
+impl InputReader<io::StdinLock<'static>, io::Stderr> {
+    /// Reads from stdin and prints prompts to stderr, keeping stdout clean for piping.
+    pub fn stdin_stderr() -> Self {
+        InputReader::new(io::stdin().lock(), io::stderr())
+    }
+}
+
+/// An iterator over the lines of a `BufRead`, created by [`lines_from`] or [`InputReader::lines`].
+///
+/// Each line has its trailing line terminator stripped, like [`read_input_from`].
+/// The iterator ends (returns `None`) on EOF, while I/O failures are
+/// yielded as `Some(Err(InputError::Io(e)))`.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::lines_from;
+///
+/// let mut names = Vec::new();
+/// for line in lines_from(Cursor::new("Alice\nBob\r\n")) {
+///     names.push(line.unwrap());
+/// }
+///
+/// assert_eq!(names, ["Alice", "Bob"]);
+/// ```
+#[derive(Debug)]
+pub struct InputLines<R> {
+    reader: R,
+}
+
+impl<R: BufRead> InputLines<R> {
+    /// Creates an iterator over the lines of `reader`.
+    pub fn new(reader: R) -> Self {
+        InputLines { reader }
+    }
+}
+
+impl<R: BufRead> Iterator for InputLines<R> {
+    type Item = Result<String, InputError<Infallible>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        read_line(&mut self.reader, TrimStyle::TrailingNewline).map_err(InputError::Io).transpose()
+    }
+}
+
+/// Creates an [`InputLines`] iterator over the lines of `reader`.
+pub fn lines_from<R: BufRead>(reader: R) -> InputLines<R> {
+    InputLines::new(reader)
+}
+
+/// An iterator that parses each line of a `BufRead`, created by [`parsed_lines`].
+///
+/// Each line is handled like [`read_input_from`] with `TrimStyle::TrailingNewline`,
+/// so a blank line is parsed as an empty string. The iterator ends (returns `None`)
+/// on EOF, while parse and I/O failures are yielded as `Some(Err(..))` and
+/// iteration can continue past them.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{parsed_lines, InputError};
+///
+/// let numbers: Vec<i32> = parsed_lines(Cursor::new("1\n2\n3\n"))
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(numbers, [1, 2, 3]);
+///
+/// let results: Vec<_> = parsed_lines::<_, i32>(Cursor::new("1\ntwo\n3\n")).collect();
+/// assert_eq!(results.len(), 3);
+/// assert!(matches!(results[0], Ok(1)));
+/// assert!(matches!(results[1], Err(InputError::Parse(_))));
+/// assert!(matches!(results[2], Ok(3)));
+/// ```
+#[derive(Debug)]
+pub struct ParsedLines<R, T> {
+    reader: R,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<R: BufRead, T> ParsedLines<R, T> {
+    /// Creates an iterator that parses each line of `reader` into `T`.
+    pub fn new(reader: R) -> Self {
+        ParsedLines { reader, _marker: PhantomData }
+    }
+}
+
+impl<R, T> Iterator for ParsedLines<R, T>
+where
+    R: BufRead,
+    T: FromStr,
+{
+    type Item = Result<T, InputError<T::Err>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match read_line(&mut self.reader, TrimStyle::TrailingNewline) {
+            Ok(Some(line)) => Some(line.parse::<T>().map_err(InputError::Parse)),
+            Ok(None) => None,
+            Err(e) => Some(Err(InputError::Io(e))),
+        }
+    }
+}
+
+/// Creates a [`ParsedLines`] iterator that parses each line of `reader` into `T`.
+pub fn parsed_lines<R: BufRead, T: FromStr>(reader: R) -> ParsedLines<R, T> {
+    ParsedLines::new(reader)
+}
+
+/// A unified error type indicating either an I/O error, a parse error, or EOF.
+#[derive(Debug)]
+pub enum InputError<E> {
+    /// An I/O error occurred (e.g., closed stdin).
+    Io(io::Error),
+    /// Failed to parse the input into the desired type.
+    Parse(E),
+    /// EOF encountered (read_line returned 0).
+    Eof,
+    /// The line was empty or contained only whitespace.
+    Empty,
+    /// The input parsed, but the value was rejected by a validation rule.
+    Validation(String),
+    /// No full line arrived before the timeout, which is carried here, expired.
+    Timeout(Duration),
+    /// The line didn't hold the expected number of values.
+    TokenCount {
+        /// How many values were expected.
+        expected: usize,
+        /// How many values the line actually held.
+        found: usize,
+    },
+    /// The value parsed, but was outside the accepted range.
+    OutOfRange {
+        /// The rejected value, as typed.
+        value: String,
+        /// The accepted range, like `1..=10`.
+        bounds: String,
+    },
+    /// The two entries of a confirmation read didn't match.
+    Mismatch,
+}
+
+impl<E> InputError<E> {
+    /// Returns `true` if this is an `InputError::Eof`.
+    ///
+    /// # Usage:
+    /// ```
+    /// use std::io::Cursor;
+    /// use input_lib::{read_input_from, PrintStyle, TrimStyle};
+    ///
+    /// let mut reader = Cursor::new("");
+    /// let err = read_input_from::<_, String>(&mut reader, None, PrintStyle::Continue, TrimStyle::Trim)
+    ///     .unwrap_err();
+    ///
+    /// assert!(err.is_eof());
+    /// assert!(!err.is_parse());
+    /// assert!(!err.is_io());
+    /// ```
+    pub fn is_eof(&self) -> bool {
+        matches!(self, InputError::Eof)
+    }
+
+    /// Returns `true` if this is an `InputError::Parse`.
+    ///
+    /// # Usage:
+    /// ```
+    /// use std::io::Cursor;
+    /// use input_lib::{read_input_from, PrintStyle, TrimStyle};
+    ///
+    /// let mut reader = Cursor::new("abc\n");
+    /// let err = read_input_from::<_, u8>(&mut reader, None, PrintStyle::Continue, TrimStyle::Trim)
+    ///     .unwrap_err();
+    ///
+    /// assert!(err.is_parse());
+    /// assert!(!err.is_eof());
+    /// assert!(!err.is_io());
+    /// ```
+    pub fn is_parse(&self) -> bool {
+        matches!(self, InputError::Parse(_))
+    }
+
+    /// Returns `true` if this is an `InputError::Io`.
+    ///
+    /// # Usage:
+    /// ```
+    /// use std::io;
+    /// use input_lib::InputError;
+    ///
+    /// let err: InputError<()> = io::Error::from(io::ErrorKind::BrokenPipe).into();
+    ///
+    /// assert!(err.is_io());
+    /// assert!(!err.is_eof());
+    /// assert!(!err.is_parse());
+    /// ```
+    pub fn is_io(&self) -> bool {
+        matches!(self, InputError::Io(_))
+    }
+
+    /// Maps the parse error with `f`, leaving every other variant unchanged.
+    ///
+    /// This is the `InputError` counterpart of `Result::map_err`, for wrapping
+    /// parse errors into an application's own error type.
+    ///
+    /// # Usage:
+    /// ```
+    /// use std::io::Cursor;
+    /// use input_lib::{read_input_from, InputError, PrintStyle, TrimStyle};
+    ///
+    /// let mut reader = Cursor::new("abc\n");
+    /// let err = read_input_from::<_, u8>(&mut reader, None, PrintStyle::Continue, TrimStyle::Trim)
+    ///     .unwrap_err()
+    ///     .map_parse(|e| format!("not an age: {}", e));
+    ///
+    /// assert!(matches!(err, InputError::Parse(msg) if msg.starts_with("not an age")));
+    ///
+    /// let eof: InputError<u8> = InputError::<String>::Eof.map_parse(|_| 0);
+    /// assert!(eof.is_eof());
+    /// ```
+    pub fn map_parse<F, E2>(self, f: F) -> InputError<E2>
+    where
+        F: FnOnce(E) -> E2,
+    {
+        match self {
+            InputError::Io(e) => InputError::Io(e),
+            InputError::Parse(e) => InputError::Parse(f(e)),
+            InputError::Eof => InputError::Eof,
+            InputError::Empty => InputError::Empty,
+            InputError::Validation(msg) => InputError::Validation(msg),
+            InputError::Timeout(timeout) => InputError::Timeout(timeout),
+            InputError::TokenCount { expected, found } => InputError::TokenCount { expected, found },
+            InputError::OutOfRange { value, bounds } => InputError::OutOfRange { value, bounds },
+            InputError::Mismatch => InputError::Mismatch,
+        }
+    }
+
+    /// Prefixes the error message with `msg`, erasing the parse error into a `String`.
+    ///
+    /// `Parse` and `Validation` messages become `"{msg}: {original}"`, and `Io`
+    /// errors keep their kind with the same prefix. Variants without a message
+    /// (`Eof`, `Empty`, `Timeout`, `TokenCount`, `Mismatch`) and `OutOfRange` are returned unchanged, so they
+    /// can still be matched on.
+    ///
+    /// # Usage:
+    /// ```
+    /// use std::io::Cursor;
+    /// use input_lib::{read_input_from, InputError, PrintStyle, TrimStyle};
+    ///
+    /// let mut reader = Cursor::new("old\n");
+    /// let err = read_input_from::<_, u8>(&mut reader, None, PrintStyle::Continue, TrimStyle::Trim)
+    ///     .map_err(|e| e.context("reading user age"))
+    ///     .unwrap_err();
+    ///
+    /// assert_eq!(err.to_string(), "Parse error: reading user age: invalid digit found in string");
+    ///
+    /// let eof = InputError::<u8>::Eof.context("reading user age");
+    /// assert!(eof.is_eof());
+    /// ```
+    pub fn context(self, msg: impl std::fmt::Display) -> InputError<String>
+    where
+        E: std::fmt::Display,
+    {
+        match self {
+            InputError::Io(e) => InputError::Io(io::Error::new(e.kind(), format!("{}: {}", msg, e))),
+            InputError::Validation(original) => InputError::Validation(format!("{}: {}", msg, original)),
+            other => other.map_parse(|e| format!("{}: {}", msg, e)),
+        }
+    }
+
+    /// Converts this error into an `io::Error`, for APIs that only accept `io::Result`.
+    ///
+    /// - `Io` returns the inner error unchanged.
+    /// - `Eof` becomes `ErrorKind::UnexpectedEof` and `Timeout` becomes `ErrorKind::TimedOut`.
+    /// - Every other variant describes bad input and becomes `ErrorKind::InvalidData`
+    ///   with this error's message (or, for `Parse`, the parse error's message).
+    ///
+    /// # Usage:
+    /// ```
+    /// use std::io::{self, ErrorKind};
+    /// use std::time::Duration;
+    /// use input_lib::InputError;
+    ///
+    /// let kind = |err: InputError<String>| err.into_io().kind();
+    ///
+    /// assert_eq!(kind(io::Error::from(ErrorKind::BrokenPipe).into()), ErrorKind::BrokenPipe);
+    /// assert_eq!(kind(InputError::Parse("bad digit".to_string())), ErrorKind::InvalidData);
+    /// assert_eq!(kind(InputError::Eof), ErrorKind::UnexpectedEof);
+    /// assert_eq!(kind(InputError::Empty), ErrorKind::InvalidData);
+    /// assert_eq!(kind(InputError::Validation("too short".to_string())), ErrorKind::InvalidData);
+    /// assert_eq!(kind(InputError::Timeout(Duration::from_secs(5))), ErrorKind::TimedOut);
+    /// assert_eq!(kind(InputError::TokenCount { expected: 2, found: 1 }), ErrorKind::InvalidData);
+    /// assert_eq!(
+    ///     kind(InputError::OutOfRange { value: "0".to_string(), bounds: "1..".to_string() }),
+    ///     ErrorKind::InvalidData,
+    /// );
+    /// assert_eq!(kind(InputError::Mismatch), ErrorKind::InvalidData);
+    ///
+    /// let err = InputError::Parse("bad digit".to_string()).into_io();
+    /// assert_eq!(err.to_string(), "bad digit");
+    /// ```
+    pub fn into_io(self) -> io::Error
+    where
+        E: std::fmt::Display + std::fmt::Debug,
+    {
+        match self {
+            InputError::Io(e) => e,
+            InputError::Parse(e) => io::Error::new(io::ErrorKind::InvalidData, e.to_string()),
+            InputError::Eof => io::Error::new(io::ErrorKind::UnexpectedEof, self.to_string()),
+            InputError::Timeout(_) => io::Error::new(io::ErrorKind::TimedOut, self.to_string()),
+            InputError::Empty
+            | InputError::Validation(_)
+            | InputError::TokenCount { .. }
+            | InputError::OutOfRange { .. }
+            | InputError::Mismatch => {
+                io::Error::new(io::ErrorKind::InvalidData, self.to_string())
+            }
+        }
+    }
+}
+
+/// Defines how the prompt should be printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrintStyle {
+    /// Print the prompt without a trailing newline (uses `print!`).
+    Continue,
+    /// Print the prompt with a trailing newline (uses `println!`).
+    NewLine,
+    /// Print the prompt to stderr without a trailing newline (uses `eprint!`),
+    /// so stdout can be piped cleanly.
+    ///
+    /// Functions that take an explicit writer print to that writer instead.
+    Stderr,
+    /// Print the prompt to stderr with a trailing newline (uses `eprintln!`).
+    ///
+    /// Functions that take an explicit writer print to that writer instead.
+    StderrNewLine,
+    /// Print the prompt without a trailing newline and without flushing.
+    ///
+    /// Avoids a flush per prompt when scripting many reads. The caller is
+    /// responsible for flushing, otherwise the prompt may not be visible
+    /// before the program blocks on input.
+    ///
+    /// ```
+    /// use std::io::{self, Cursor, Write};
+    /// use input_lib::{read_input_from_with_writer, PrintStyle, TrimStyle};
+    ///
+    /// #[derive(Default)]
+    /// struct CountingWriter {
+    ///     bytes: Vec<u8>,
+    ///     flushes: usize,
+    /// }
+    ///
+    /// impl Write for CountingWriter {
+    ///     fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+    ///         self.bytes.extend_from_slice(buf);
+    ///         Ok(buf.len())
+    ///     }
+    ///     fn flush(&mut self) -> io::Result<()> {
+    ///         self.flushes += 1;
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let mut reader = Cursor::new("1\n".repeat(1000));
+    /// let mut writer = CountingWriter::default();
+    /// let mut sum = 0;
+    /// for _ in 0..1000 {
+    ///     let n: u32 = read_input_from_with_writer(
+    ///         &mut reader,
+    ///         &mut writer,
+    ///         Some(format_args!("> ")),
+    ///         PrintStyle::NoFlush,
+    ///         TrimStyle::TrailingNewline,
+    ///     ).unwrap();
+    ///     sum += n;
+    /// }
+    ///
+    /// assert_eq!(sum, 1000);
+    /// assert_eq!(writer.bytes, "> ".repeat(1000).as_bytes());
+    /// assert_eq!(writer.flushes, 0);
+    /// ```
+    NoFlush,
+    /// Don't print the prompt at all, even when one is given.
+    ///
+    /// Lets a caller decide once whether prompts are wanted, for example from a
+    /// `--quiet` flag, and pass the style along without touching the prompts.
+    ///
+    /// ```
+    /// use std::io::Cursor;
+    /// use input_lib::{read_input_from_with_writer, PrintStyle, TrimStyle};
+    ///
+    /// let verbose = false;
+    /// let style = if verbose { PrintStyle::Continue } else { PrintStyle::Silent };
+    ///
+    /// let mut output = Vec::new();
+    /// let n: u32 = read_input_from_with_writer(
+    ///     &mut Cursor::new("7\n"),
+    ///     &mut output,
+    ///     Some(format_args!("Count: ")),
+    ///     style,
+    ///     TrimStyle::Trim,
+    /// ).unwrap();
+    ///
+    /// assert_eq!(n, 7);
+    /// assert!(output.is_empty());
+    /// ```
+    Silent,
+}
+
+/// Defines how much whitespace is removed from a line before it is parsed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrimStyle {
+    /// Strip the line terminator (`\n` or `\r\n`), keeping other whitespace.
+    TrailingNewline,
+    /// Strip all leading and trailing whitespace (uses `str::trim`).
+    Trim,
+    /// Strip all trailing whitespace, keeping indentation (uses `str::trim_end`).
+    End,
+}
+
+impl TrimStyle {
+    /// Applies this trimming style to a line.
+    ///
+    /// # Usage:
+    /// ```
+    /// use input_lib::TrimStyle;
+    ///
+    /// let line = "  hello \r\n";
+    /// assert_eq!(TrimStyle::TrailingNewline.apply(line), "  hello ");
+    /// assert_eq!(TrimStyle::Trim.apply(line), "hello");
+    /// assert_eq!(TrimStyle::End.apply(line), "  hello");
+    /// assert_eq!(TrimStyle::TrailingNewline.apply("hello\r\r\n"), "hello\r");
+    ///
+    /// // Pasted numbers often carry stray spaces, which `Trim` removes before parsing.
+    /// use std::io::Cursor;
+    /// use input_lib::{read_input_from, PrintStyle};
+    ///
+    /// let mut reader = Cursor::new("  42 \n  42 \n");
+    /// let trimmed: Result<i32, _> = read_input_from(&mut reader, None, PrintStyle::Continue, TrimStyle::Trim);
+    /// let untrimmed: Result<i32, _> = read_input_from(&mut reader, None, PrintStyle::Continue, TrimStyle::TrailingNewline);
+    ///
+    /// assert_eq!(trimmed.unwrap(), 42);
+    /// assert!(untrimmed.unwrap_err().is_parse());
+    /// ```
+    pub fn apply(self, line: &str) -> &str {
+        match self {
+            TrimStyle::Trim => line.trim(),
+            TrimStyle::End => line.trim_end(),
+            TrimStyle::TrailingNewline => {
+                let line = line.strip_suffix('\n').unwrap_or(line);
+                line.strip_suffix('\r').unwrap_or(line)
+            }
+        }
+    }
+}
+
+impl<E: std::fmt::Display + std::fmt::Debug> std::fmt::Display for InputError<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            InputError::Io(e) => write!(f, "I/O error: {}", e),
+            InputError::Parse(e) => write!(f, "Parse error: {}", e),
+            InputError::Eof => write!(f, "EOF encountered"),
+            InputError::Empty => write!(f, "empty input"),
+            InputError::Validation(msg) => write!(f, "Validation error: {}", msg),
+            InputError::Timeout(timeout) => write!(f, "timed out after {:?} waiting for input", timeout),
+            InputError::TokenCount { expected, found } => {
+                write!(f, "expected {} values, found {}", expected, found)
+            }
+            InputError::OutOfRange { value, bounds } => {
+                write!(f, "{} is out of range {}", value, bounds)
+            }
+            InputError::Mismatch => write!(f, "inputs do not match"),
+        }
+    }
+}
+
+impl<E: std::error::Error + 'static> InputError<E> {
+    /// Returns the error this one wraps: the `io::Error` of `InputError::Io`,
+    /// or the parse error of `InputError::Parse`.
+    ///
+    /// [`Error::source`](std::error::Error::source) can only return the
+    /// former, because `InputError<E>` is an `Error` for any displayable `E`,
+    /// including `String`. This method covers parse errors that are `Error`s
+    /// themselves.
+    ///
+    /// # Usage:
+    /// ```
+    /// use std::io::Cursor;
+    /// use std::num::ParseIntError;
+    /// use input_lib::{read_input_from, PrintStyle, TrimStyle};
+    ///
+    /// let err = read_input_from::<_, u8>(&mut Cursor::new("abc\n"), None, PrintStyle::Continue, TrimStyle::Trim)
+    ///     .unwrap_err();
+    /// let inner = err.source_error().unwrap();
+    ///
+    /// assert!(inner.is::<ParseIntError>());
+    /// assert_eq!(inner.to_string(), "invalid digit found in string");
+    /// ```
+    pub fn source_error(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Parse(e) => Some(e),
+            _ => std::error::Error::source(self),
+        }
+    }
+}
+
+/// `source()` returns the underlying `io::Error` for `InputError::Io`.
+///
+/// The impl only requires `E: Display + Debug`, so that errors carrying a
+/// `String` message (like `InputError<String>`) are `Error`s too and work
+/// with `?` into `Box<dyn Error>`. For a parse error that is itself an
+/// `Error`, [`InputError::source_error`] returns it as well.
+///
+/// # Usage:
+/// ```
+/// use std::error::Error;
+/// use std::io;
+/// use std::time::Duration;
+/// use input_lib::InputError;
+///
+/// let err: InputError<String> = io::Error::new(io::ErrorKind::BrokenPipe, "closed").into();
+/// let source = err.source().unwrap();
+/// assert_eq!(source.downcast_ref::<io::Error>().unwrap().kind(), io::ErrorKind::BrokenPipe);
+///
+/// // Every other variant ends the chain; the parse error is still part of `Display`.
+/// let parse = InputError::Parse("abc".parse::<u8>().unwrap_err());
+/// assert!(parse.source().is_none());
+/// assert_eq!(parse.to_string(), "Parse error: invalid digit found in string");
+///
+/// for err in [
+///     InputError::<String>::Eof,
+///     InputError::Empty,
+///     InputError::Validation("too short".to_string()),
+///     InputError::Timeout(Duration::from_secs(5)),
+///     InputError::TokenCount { expected: 2, found: 1 },
+///     InputError::OutOfRange { value: "0".to_string(), bounds: "1..".to_string() },
+///     InputError::Mismatch,
+/// ] {
+///     assert!(err.source().is_none());
+/// }
+/// ```
+impl<E: std::fmt::Display + std::fmt::Debug> std::error::Error for InputError<E> {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InputError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Converts an I/O error into `InputError::Io`, so `?` works on raw I/O calls.
+///
+/// # Usage:
+/// ```
+/// use std::io::{self, Write};
+/// use std::num::ParseIntError;
+/// use input_lib::InputError;
+///
+/// fn greet(out: &mut impl Write) -> Result<(), InputError<ParseIntError>> {
+///     writeln!(out, "Hello!")?;
+///     Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))?
+/// }
+///
+/// let err = greet(&mut Vec::new()).unwrap_err();
+/// assert!(matches!(err, InputError::Io(e) if e.kind() == io::ErrorKind::BrokenPipe));
+///
+/// // The standard parse errors convert into `InputError::Parse`.
+/// fn double(text: &str) -> Result<i32, InputError<ParseIntError>> {
+///     Ok(text.trim().parse::<i32>()? * 2)
+/// }
+///
+/// assert_eq!(double(" 21 ").unwrap(), 42);
+/// assert!(double("x").unwrap_err().is_parse());
+/// ```
+impl<E> From<io::Error> for InputError<E> {
+    fn from(error: io::Error) -> Self {
+        InputError::Io(error)
+    }
+}
+
+/// Implements `From<E> for InputError<E>`, converting into `InputError::Parse`,
+/// for error types that can't be confused with `io::Error`.
+///
+/// A blanket `impl<E> From<E>` would conflict with `From<io::Error>` (and with
+/// `From<T> for T`), so only these concrete types get one.
+macro_rules! impl_from_parse_error {
+    ($($ty:ty),+ $(,)?) => {
+        $(
+            impl From<$ty> for InputError<$ty> {
+                fn from(error: $ty) -> Self {
+                    InputError::Parse(error)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_parse_error!(
+    std::num::ParseIntError,
+    std::num::ParseFloatError,
+    std::str::ParseBoolError,
+    std::char::ParseCharError,
+    std::net::AddrParseError,
+);
+
+/// Clones the error. `io::Error` isn't `Clone`, so an `Io` error is rebuilt from
+/// its kind and message; any custom inner error is lost.
+///
+/// # Usage:
+/// ```
+/// use std::io;
+/// use input_lib::InputError;
+///
+/// let err: InputError<String> = io::Error::new(io::ErrorKind::BrokenPipe, "closed").into();
+/// let copy = err.clone();
+///
+/// assert!(matches!(&copy, InputError::Io(e) if e.kind() == io::ErrorKind::BrokenPipe));
+/// assert_eq!(copy.to_string(), err.to_string());
+/// ```
+impl<E: Clone> Clone for InputError<E> {
+    fn clone(&self) -> Self {
+        match self {
+            InputError::Io(e) => InputError::Io(io::Error::new(e.kind(), e.to_string())),
+            InputError::Parse(e) => InputError::Parse(e.clone()),
+            InputError::Eof => InputError::Eof,
+            InputError::Empty => InputError::Empty,
+            InputError::Validation(msg) => InputError::Validation(msg.clone()),
+            InputError::Timeout(timeout) => InputError::Timeout(*timeout),
+            InputError::TokenCount { expected, found } => {
+                InputError::TokenCount { expected: *expected, found: *found }
+            }
+            InputError::OutOfRange { value, bounds } => {
+                InputError::OutOfRange { value: value.clone(), bounds: bounds.clone() }
+            }
+            InputError::Mismatch => InputError::Mismatch,
+        }
+    }
+}
+
+/// Compares two errors. `Io` errors are equal when their `ErrorKind`s are,
+/// regardless of the message.
+///
+/// # Usage:
+/// ```
+/// use std::io::{self, Cursor};
+/// use input_lib::{read_input_from, InputError, PrintStyle, TrimStyle};
+///
+/// let mut reader = Cursor::new("");
+/// let result = read_input_from::<_, u8>(&mut reader, None, PrintStyle::Continue, TrimStyle::Trim);
+/// assert_eq!(result.unwrap_err(), InputError::Eof);
+///
+/// let mut reader = Cursor::new("abc\n");
+/// let result = read_input_from::<_, u8>(&mut reader, None, PrintStyle::Continue, TrimStyle::Trim);
+/// let expected = "abc".parse::<u8>().unwrap_err();
+/// assert_eq!(result.unwrap_err(), InputError::Parse(expected));
+/// assert_ne!(InputError::Parse("a".to_string()), InputError::Parse("b".to_string()));
+/// assert_ne!(InputError::<String>::Eof, InputError::Empty);
+///
+/// let a: InputError<()> = io::Error::new(io::ErrorKind::BrokenPipe, "first").into();
+/// let b: InputError<()> = io::Error::new(io::ErrorKind::BrokenPipe, "second").into();
+/// assert_eq!(a, b);
+/// ```
+impl<E: PartialEq> PartialEq for InputError<E> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (InputError::Io(a), InputError::Io(b)) => a.kind() == b.kind(),
+            (InputError::Parse(a), InputError::Parse(b)) => a == b,
+            (InputError::Eof, InputError::Eof) => true,
+            (InputError::Empty, InputError::Empty) => true,
+            (InputError::Validation(a), InputError::Validation(b)) => a == b,
+            (InputError::Timeout(a), InputError::Timeout(b)) => a == b,
+            (
+                InputError::TokenCount { expected: a_expected, found: a_found },
+                InputError::TokenCount { expected: b_expected, found: b_found },
+            ) => a_expected == b_expected && a_found == b_found,
+            (
+                InputError::OutOfRange { value: a_value, bounds: a_bounds },
+                InputError::OutOfRange { value: b_value, bounds: b_bounds },
+            ) => a_value == b_value && a_bounds == b_bounds,
+            (InputError::Mismatch, InputError::Mismatch) => true,
+            _ => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::{BufRead, BufReader, Cursor};
+
+    use super::*;
+
+    // The closures are never called; they only need to compile.
+    #[test]
+    fn input_macros_take_macro_built_prompts() {
+        use std::convert::Infallible;
+
+        let _ = || -> Result<String, InputError<Infallible>> { input!(concat!("Count", ": ")) };
+        let _ = || -> Result<String, InputError<Infallible>> { inputln!(concat!("Count", ": ")) };
+        let _ = || -> Result<String, InputError<Infallible>> { inputerr!(concat!("Count", ": ")) };
+        let _ = || -> Result<String, InputError<Infallible>> { inputlnerr!(concat!("Count", ": ")) };
+        let _ = || input!(as u32, concat!("Count", ": "));
+        let _ = || input!(as u32);
+        let _ = || inputlnerr!(as std::net::IpAddr, "{}: ", "Address");
+    }
+
+    #[test]
+    fn input_until_keeps_formatted_prompts() {
+        let attempts = 3;
+        let _ = || -> Result<i32, _> { input_until!("{}, {}: ", 3, "number") };
+        let _ = || -> Result<i32, _> { input_until!(max = attempts, "Attempt {} of {}: ", 1, attempts) };
+        let _ = || -> Result<i32, _> { input_until!(max = 3) };
+    }
+
+    #[test]
+    fn input_collect_keeps_format_arguments() {
+        let name = "Alice";
+        let _ = || -> Result<Vec<u32>, _> { input_collect!(2, "Score for {}: ", name) };
+        let _ = || -> Result<Vec<u32>, _> { input_collect!(2, |i| "Score {} for {}: ", i, name) };
+    }
+
+    #[test]
+    fn strip_bom_survives_small_buffers() {
+        for capacity in 1..=4 {
+            let inner = BufReader::with_capacity(capacity, Cursor::new("\u{FEFF}42\n\u{FEFF}7\n"));
+            let mut reader = StripBom::new(inner);
+
+            let mut first = String::new();
+            BufRead::read_line(&mut reader, &mut first).unwrap();
+            assert_eq!(first, "42\n", "capacity {capacity}");
+
+            let mut second = String::new();
+            BufRead::read_line(&mut reader, &mut second).unwrap();
+            assert_eq!(second, "\u{FEFF}7\n", "capacity {capacity}");
+        }
+    }
+
+    #[test]
+    fn strip_bom_keeps_a_partial_mark() {
+        for input in ["\u{EF}x\n", "", "\u{FE}\n"] {
+            for capacity in 1..=4 {
+                let mut reader = StripBom::new(BufReader::with_capacity(capacity, Cursor::new(input)));
+                let mut line = String::new();
+                BufRead::read_line(&mut reader, &mut line).unwrap();
+                assert_eq!(line, input, "capacity {capacity}");
+            }
+        }
+
+        let mut reader = StripBom::new(BufReader::with_capacity(1, Cursor::new(&b"\xEF\xBB\n"[..])));
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).unwrap();
+        assert_eq!(bytes, b"\xEF\xBB\n");
+    }
+
+    #[test]
+    fn value_readers_trim_like_input() {
+        let line = || Cursor::new(" 5 \n");
+        let style = PrintStyle::Continue;
+
+        assert_eq!(read_validated_from(&mut line(), None, style, |n: &u8| *n < 10).unwrap(), 5);
+        assert_eq!(read_nonempty_from::<_, u8>(&mut line(), None, style).unwrap(), 5);
+        assert_eq!(read_with_default(&mut line(), None, style, 0u8).unwrap(), 5);
+        assert_eq!(read_optional::<_, u8>(&mut line(), None, style).unwrap(), Some(5));
+        assert_eq!(read_or_else(&mut line(), None, style, || 0u8).unwrap(), 5);
+        assert_eq!(read_one_of(&mut line(), None, style, &[5u8]).unwrap(), 5);
+        assert_eq!(read_bounded_number(&mut line(), None, style, 1u8, 9).unwrap(), 5);
+        assert_eq!(read_with_retry::<_, _, u8>(&mut line(), &mut Vec::new(), None, 1).unwrap(), 5);
+        assert_eq!(read_with_attempts::<_, u8>(&mut line(), None, style, 1).unwrap(), (5, 1));
+
+        let mut input = InputReader::new(line(), Vec::new());
+        assert_eq!(input.read::<u8>("", style).unwrap(), 5);
+    }
+
+    #[test]
+    fn read_with_writer_captures_the_prompt_for_each_style() {
+        let cases = [
+            (PrintStyle::Continue, "Age: "),
+            (PrintStyle::NewLine, "Age: \n"),
+            (PrintStyle::Stderr, "Age: "),
+            (PrintStyle::StderrNewLine, "Age: \n"),
+            (PrintStyle::NoFlush, "Age: "),
+            (PrintStyle::Silent, ""),
+        ];
+        for (style, expected) in cases {
+            let mut output = Vec::new();
+            let age: u8 =
+                read_input_from_with_writer(&mut Cursor::new("30\n"), &mut output, Some(format_args!("Age: ")), style, TrimStyle::Trim)
+                    .unwrap();
+            assert_eq!(age, 30, "{style:?}");
+            assert_eq!(output, expected.as_bytes(), "{style:?}");
+        }
+    }
+
+    #[test]
+    fn read_with_writer_interleaves_prompts_and_lines() {
+        let mut reader = Cursor::new("Alice\nabc\n");
+        let mut output = Vec::new();
+
+        let name: String =
+            read_input_from_with_writer(&mut reader, &mut output, Some(format_args!("Name: ")), PrintStyle::Continue, TrimStyle::Trim)
+                .unwrap();
+        let age = read_input_from_with_writer::<_, _, u8>(
+            &mut reader,
+            &mut output,
+            Some(format_args!("{}'s age? ", name)),
+            PrintStyle::NewLine,
+            TrimStyle::Trim,
+        );
+        let eof = read_input_from_with_writer::<_, _, u8>(&mut reader, &mut output, Some(format_args!("More: ")), PrintStyle::Continue, TrimStyle::Trim);
+
+        assert_eq!(name, "Alice");
+        assert!(age.unwrap_err().is_parse());
+        assert!(eof.unwrap_err().is_eof());
+        assert_eq!(output, b"Name: Alice's age? \nMore: ");
+    }
+
+    #[test]
+    fn delimited_readers_split_alike() {
+        let style = PrintStyle::Continue;
+        for line in ["1, 2, 3,\n", " 1 ,2,3\n"] {
+            let plain: Vec<u8> = read_vec_delimited_from(&mut Cursor::new(line), None, style, ',').unwrap();
+            let named: Vec<u8> = read_delimited_from(&mut Cursor::new(line), None, style, ',').unwrap();
+            assert_eq!(plain, [1, 2, 3]);
+            assert_eq!(named, plain);
+        }
+        assert!(read_vec_delimited_from::<_, u8>(&mut Cursor::new("  \n"), None, style, ',').unwrap().is_empty());
+    }
+
+    #[test]
+    fn inputln_prompt_ends_the_line() {
+        let mut output = Vec::new();
+        let color: String = read_input_from_with_writer(
+            &mut Cursor::new("blue\n"),
+            &mut output,
+            Some(format_args!("What's your favorite color?")),
+            PrintStyle::NewLine,
+            TrimStyle::Trim,
+        )
+        .unwrap();
+
+        assert_eq!(color, "blue");
+        assert_eq!(output, b"What's your favorite color?\n");
+
+        let user = "Alice";
+        let _ = || -> Result<String, InputError<std::convert::Infallible>> { inputln!("What's {}'s favorite color?", user) };
+    }
+
+    #[test]
+    fn line_readers_survive_small_buffers() {
+        for capacity in 1..=3 {
+            let small = |text: &'static str| BufReader::with_capacity(capacity, Cursor::new(text));
+
+            let mut reader = small("4\r\n 2 \r\n");
+            let first: u8 = read_input_from(&mut reader, None, PrintStyle::Continue, TrimStyle::Trim).unwrap();
+            let second: u8 = read_input_from(&mut reader, None, PrintStyle::Continue, TrimStyle::Trim).unwrap();
+            assert_eq!((first, second), (4, 2), "capacity {capacity}");
+
+            let mut reader = small("12;345;6");
+            let records: Vec<u32> = (0..3)
+                .map(|_| read_until_delimiter_from(&mut reader, None, PrintStyle::Continue, b';').unwrap())
+                .collect();
+            assert_eq!(records, [12, 345, 6], "capacity {capacity}");
+
+            let mut reader = small("ab\r\ncd\n\nef\n");
+            assert_eq!(read_block_from(&mut reader, None).unwrap(), "ab\ncd", "capacity {capacity}");
+            let rest: Vec<String> = lines_from(reader).map(Result::unwrap).collect();
+            assert_eq!(rest, ["ef"], "capacity {capacity}");
+        }
+    }
+
+    #[test]
+    fn error_chain_covers_every_variant() {
+        use std::error::Error;
+        use std::num::ParseIntError;
+
+        let io: InputError<ParseIntError> = io::Error::new(io::ErrorKind::BrokenPipe, "closed").into();
+        assert_eq!(io.source().unwrap().downcast_ref::<io::Error>().unwrap().kind(), io::ErrorKind::BrokenPipe);
+        assert!(io.source_error().unwrap().is::<io::Error>());
+
+        let parse = InputError::Parse("abc".parse::<u8>().unwrap_err());
+        assert!(parse.source().is_none());
+        assert!(parse.source_error().unwrap().is::<ParseIntError>());
+
+        for err in [
+            InputError::<ParseIntError>::Eof,
+            InputError::Empty,
+            InputError::Validation("too short".to_string()),
+            InputError::Timeout(Duration::from_secs(5)),
+            InputError::TokenCount { expected: 2, found: 1 },
+            InputError::OutOfRange { value: "0".to_string(), bounds: "1..".to_string() },
+            InputError::Mismatch,
+        ] {
+            assert!(err.source().is_none(), "{err}");
+            assert!(err.source_error().is_none(), "{err}");
+        }
+    }
+
+    #[test]
+    fn indented_block_survives_small_buffers() {
+        for capacity in 1..=8 {
+            let inner = BufReader::with_capacity(capacity, Cursor::new("    ab\n    cd\n    ef\n  outer\n"));
+            let mut reader = PeekReader::new(inner);
+
+            let block = read_indented_block_from(&mut reader, None, 4).unwrap();
+            assert_eq!(block, ["ab", "cd", "ef"], "capacity {capacity}");
+
+            let mut next = String::new();
+            BufRead::read_line(&mut reader, &mut next).unwrap();
+            assert_eq!(next, "  outer\n", "capacity {capacity}");
+        }
+    }
+
+    #[test]
+    fn indented_block_ends_at_eof() {
+        let mut reader = PeekReader::new(BufReader::with_capacity(8, Cursor::new("    ab\n    cd\n    ef\n")));
+        assert_eq!(read_indented_block_from(&mut reader, None, 4).unwrap(), ["ab", "cd", "ef"]);
+        assert!(read_indented_block_from(&mut reader, None, 4).unwrap().is_empty());
+    }
+
+    #[test]
+    fn peek_reader_serves_peeked_bytes_before_the_inner_reader() {
+        let mut reader = PeekReader::new(BufReader::with_capacity(2, Cursor::new("abcdef")));
+        assert_eq!(reader.peek(5).unwrap(), b"abcde");
+
+        let mut rest = String::new();
+        reader.read_to_string(&mut rest).unwrap();
+        assert_eq!(rest, "abcdef");
+    }
+}
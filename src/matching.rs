@@ -0,0 +1,85 @@
+//! Validating lines against regular expressions.
+
+use std::{convert::Infallible, fmt::Arguments, io::BufRead};
+
+use regex::Regex;
+
+use crate::{InputError, PrintStyle, TrimStyle, print_prompt, read_line};
+
+/// Reads a line that must match `pattern` in full:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Reads one line and trims surrounding whitespace.
+/// 3. Returns `Err(InputError::Validation(msg))` unless a match of `pattern`
+///    spans the whole trimmed line.
+/// 4. Otherwise returns the trimmed line.
+///
+/// The line is checked against `pattern` wrapped as `\A(?:pattern)\z`, so
+/// alternatives like `a|ab` match `ab` too. The wrapped regex is built from
+/// `pattern.as_str()`: options set on a `RegexBuilder` do not carry over, so
+/// use inline flags like `(?i)` instead.
+/// Wrap the call in [`retry_with`](crate::retry_with) to re-prompt on a mismatch.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_matching, InputError, PrintStyle};
+/// use regex::Regex;
+///
+/// let phone = Regex::new(r"\+?[0-9]{3}-[0-9]{4}").unwrap();
+/// let mut reader = Cursor::new(" 555-1234 \n555-1234 ext. 5\n");
+///
+/// let number = read_matching(&mut reader, None, PrintStyle::Continue, &phone).unwrap();
+/// assert_eq!(number, "555-1234");
+///
+/// let partial = read_matching(&mut reader, None, PrintStyle::Continue, &phone);
+/// assert!(matches!(partial, Err(InputError::Validation(_))));
+/// ```
+pub fn read_matching<R: BufRead>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    print_style: PrintStyle,
+    pattern: &Regex,
+) -> Result<String, InputError<Infallible>> {
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, print_style)?;
+    }
+
+    let input = read_line(reader, TrimStyle::Trim)?.ok_or(InputError::Eof)?;
+    let anchored = Regex::new(&format!(r"\A(?:{})\z", pattern.as_str()))
+        .map_err(|e| InputError::Validation(e.to_string()))?;
+    if !anchored.is_match(&input) {
+        return Err(InputError::Validation(format!(
+            "'{}' does not match the pattern {}",
+            input,
+            pattern.as_str()
+        )));
+    }
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn later_alternatives_can_match_the_whole_line() {
+        let pattern = Regex::new("a|ab").unwrap();
+        let mut reader = Cursor::new("ab\nabc\n");
+
+        assert_eq!(read_matching(&mut reader, None, PrintStyle::Continue, &pattern).unwrap(), "ab");
+        assert!(matches!(
+            read_matching(&mut reader, None, PrintStyle::Continue, &pattern),
+            Err(InputError::Validation(_))
+        ));
+    }
+
+    #[test]
+    fn inline_flags_are_kept() {
+        let pattern = Regex::new("(?i)yes|no").unwrap();
+        let mut reader = Cursor::new("YES\n");
+
+        assert_eq!(read_matching(&mut reader, None, PrintStyle::Continue, &pattern).unwrap(), "YES");
+    }
+}
@@ -0,0 +1,440 @@
+//! Reading secrets without echoing them to the terminal.
+//!
+//! The stdin entry points ([`password!`](crate::password), [`read_password`],
+//! [`read_password_confirmed`] and [`read_password_masked`]) switch terminal
+//! echo off while the secret is typed (via termios on Unix and the console API
+//! on Windows) and restore it afterwards, even if the read fails or panics.
+//! When stdin is not a terminal, for example when input is piped in, the
+//! secret is read as a plain line.
+//!
+//! The `_from` functions take any reader and never touch the terminal, since
+//! the reader may have nothing to do with it.
+
+use std::{
+    convert::Infallible,
+    fmt::Arguments,
+    io::{self, BufRead, IsTerminal, Read, Write},
+};
+
+use crate::{InputError, PrintStyle, TrimStyle, print_prompt, write_prompt};
+
+/// A macro that:
+/// - optionally prints a prompt (with `print!`),
+/// - reads **one line** from stdin without echoing it,
+/// - returns `Err(InputError::Eof)` if EOF is encountered,
+/// - returns `Err(InputError::Io(e))` if an IO error occurs.
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::password;
+///
+/// let secret = password!("Password: ").unwrap();
+/// ```
+#[macro_export]
+macro_rules! password {
+    () => {{
+        $crate::read_password(None)
+    }};
+    ($($arg:tt)*) => {{
+        $crate::read_password(Some(format_args!($($arg)*)))
+    }};
+}
+
+/// A single function that:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Turns off terminal echo, if stdin is a terminal.
+/// 3. Reads one line from stdin, stripping the line terminator.
+/// 4. Restores terminal echo.
+/// 5. Returns `Err(InputError::Eof)` if EOF is reached.
+/// 6. Returns `Err(InputError::Io)` on I/O failure.
+///
+/// Only the line terminator is removed; any other whitespace is part of the secret.
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::read_password;
+///
+/// let secret = read_password(Some(format_args!("Password: "))).unwrap();
+/// ```
+pub fn read_password(prompt: Option<Arguments<'_>>) -> Result<String, InputError<Infallible>> {
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, PrintStyle::Continue)?;
+    }
+
+    let guard = EchoGuard::disable(false)?;
+    let mut secret = String::new();
+    let read = io::stdin().lock().read_line(&mut secret);
+    if guard.is_some() {
+        drop(guard);
+        // The user's Enter key wasn't echoed either, so end the prompt line ourselves.
+        let mut stdout = io::stdout();
+        writeln!(stdout)?;
+        stdout.flush()?;
+    }
+
+    if read? == 0 {
+        return Err(InputError::Eof);
+    }
+    Ok(TrimStyle::TrailingNewline.apply(&secret).to_owned())
+}
+
+/// Like [`read_password`], but reads from `reader` instead of stdin:
+/// 1. Optionally prints a prompt to stdout (and flushes).
+/// 2. Reads one line from the provided `BufRead`, stripping the line terminator.
+/// 3. Returns `Err(InputError::Eof)` if EOF is reached.
+/// 4. Returns `Err(InputError::Io)` on I/O failure.
+///
+/// `reader` isn't necessarily the terminal, so echo is left alone.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::read_password_from;
+///
+/// let mut reader = Cursor::new("hunter2 \n");
+/// let secret = read_password_from(&mut reader, None).unwrap();
+///
+/// assert_eq!(secret, "hunter2 ");
+/// ```
+pub fn read_password_from<R: BufRead>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+) -> Result<String, InputError<Infallible>> {
+    let secret = read_secret_from(reader, &mut io::stdout(), prompt)?;
+    Ok(TrimStyle::TrailingNewline.apply(&secret).to_owned())
+}
+
+/// Like [`read_password_from`], but prints the prompt to `writer` instead of
+/// stdout and returns the line exactly as read, terminator included.
+///
+/// Nothing but the prompt is written to `writer`.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::read_secret_from;
+///
+/// let mut reader = Cursor::new("hunter2 \r\n");
+/// let mut output = Vec::new();
+/// let secret = read_secret_from(&mut reader, &mut output, Some(format_args!("Token: "))).unwrap();
+///
+/// assert_eq!(secret, "hunter2 \r\n");
+/// assert_eq!(output, b"Token: ");
+/// ```
+pub fn read_secret_from<R: BufRead, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    prompt: Option<Arguments<'_>>,
+) -> Result<String, InputError<Infallible>> {
+    if let Some(prompt_args) = prompt {
+        write_prompt(writer, prompt_args, PrintStyle::Continue)?;
+    }
+
+    let mut secret = String::new();
+    if reader.read_line(&mut secret)? == 0 {
+        return Err(InputError::Eof);
+    }
+    Ok(secret)
+}
+
+/// Reads a new secret twice, as in "enter password, confirm password":
+/// 1. Reads the secret with [`read_password`], printing `prompt`.
+/// 2. Reads it again, printing `confirm_prompt`.
+/// 3. Returns `Err(InputError::Mismatch)` if the two entries differ.
+/// 4. Otherwise returns the secret.
+///
+/// The entries are compared in constant time, so how long the comparison
+/// takes doesn't reveal where they differ. Wrap the call in
+/// [`retry_with`](crate::retry_with) to ask again after a mismatch.
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::read_password_confirmed;
+///
+/// let secret = read_password_confirmed(
+///     Some(format_args!("New password: ")),
+///     Some(format_args!("Repeat it: ")),
+/// ).unwrap();
+/// ```
+pub fn read_password_confirmed(
+    prompt: Option<Arguments<'_>>,
+    confirm_prompt: Option<Arguments<'_>>,
+) -> Result<String, InputError<Infallible>> {
+    let secret = read_password(prompt)?;
+    let confirmation = read_password(confirm_prompt)?;
+    confirm_secret(secret, confirmation)
+}
+
+/// Like [`read_password_confirmed`], but reads both entries from `reader`
+/// with [`read_password_from`], leaving terminal echo alone.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::{read_password_confirmed_from, retry_with, InputError};
+///
+/// let mut reader = Cursor::new("hunter2\nhunter2\n");
+/// let secret = read_password_confirmed_from(&mut reader, None, None).unwrap();
+/// assert_eq!(secret, "hunter2");
+///
+/// let mut reader = Cursor::new("hunter2\nhunter3\n");
+/// let secret = read_password_confirmed_from(&mut reader, None, None);
+/// assert_eq!(secret.unwrap_err(), InputError::Mismatch);
+///
+/// let mut reader = Cursor::new("hunter2\nhunter3\nswordfish\nswordfish\n");
+/// let mut output = Vec::new();
+/// let secret = retry_with(&mut output, 3, |_| read_password_confirmed_from(&mut reader, None, None)).unwrap();
+/// assert_eq!(secret, "swordfish");
+/// assert_eq!(output, b"inputs do not match\n");
+/// ```
+pub fn read_password_confirmed_from<R: BufRead>(
+    reader: &mut R,
+    prompt: Option<Arguments<'_>>,
+    confirm_prompt: Option<Arguments<'_>>,
+) -> Result<String, InputError<Infallible>> {
+    let secret = read_password_from(reader, prompt)?;
+    let confirmation = read_password_from(reader, confirm_prompt)?;
+    confirm_secret(secret, confirmation)
+}
+
+/// Returns `secret` if `confirmation` matches it, compared in constant time.
+fn confirm_secret(secret: String, confirmation: String) -> Result<String, InputError<Infallible>> {
+    if !constant_time_eq(secret.as_bytes(), confirmation.as_bytes()) {
+        return Err(InputError::Mismatch);
+    }
+    Ok(secret)
+}
+
+/// Like [`read_password_from`], but reads from stdin and echoes `mask` for every
+/// character typed, so the user can see their progress:
+/// 1. Optionally prints a prompt (and flushes).
+/// 2. Switches the terminal to unbuffered, non-echoing input.
+/// 3. Reads keypresses with [`read_masked_from`] until Enter is pressed.
+/// 4. Restores the terminal, even if the read fails.
+///
+/// When stdin is not a terminal, nothing is echoed and the secret is read as a
+/// plain line, exactly like [`read_password`].
+///
+/// # Usage:
+/// ```no_run
+/// use input_lib::read_password_masked;
+///
+/// let secret = read_password_masked(Some(format_args!("Password: ")), '*').unwrap();
+/// ```
+pub fn read_password_masked(prompt: Option<Arguments<'_>>, mask: char) -> Result<String, InputError<Infallible>> {
+    let mut stdin = io::stdin().lock();
+    if !io::stdin().is_terminal() {
+        return read_password_from(&mut stdin, prompt);
+    }
+
+    if let Some(prompt_args) = prompt {
+        print_prompt(prompt_args, PrintStyle::Continue)?;
+    }
+    let _guard = EchoGuard::disable(true)?;
+    read_masked_from(&mut stdin, &mut io::stdout(), mask)
+}
+
+/// Reads a secret one keypress at a time from `reader`, echoing `mask` to `writer`:
+/// 1. Writes `mask` for every character received (and flushes).
+/// 2. On backspace (`0x08` or `0x7F`), drops the last character and erases its mask.
+/// 3. Stops at `\r` or `\n`, writes a newline and returns the secret without it.
+/// 4. Also stops at EOF or Ctrl-D, returning `Err(InputError::Eof)` if nothing was typed.
+/// 5. Returns `Err(InputError::Io)` on I/O failure or if the secret isn't valid UTF-8.
+///
+/// This is the keypress handling behind [`read_password_masked`]; `reader`
+/// must deliver keys as they are pressed, like a terminal in raw mode.
+///
+/// # Usage:
+/// ```
+/// use std::io::Cursor;
+/// use input_lib::read_masked_from;
+///
+/// // Types "pass", erases the last two keys, then types "ss!" and presses Enter.
+/// let mut keys = Cursor::new(b"pass\x7f\x7fss!\r".to_vec());
+/// let mut screen = Vec::new();
+///
+/// let secret = read_masked_from(&mut keys, &mut screen, '*').unwrap();
+///
+/// assert_eq!(secret, "pass!");
+/// assert_eq!(screen, b"****\x08 \x08\x08 \x08***\n");
+///
+/// // Backspace on an empty line is ignored, and multi-byte characters get one mask.
+/// let mut keys = Cursor::new("\x08é€\n".as_bytes().to_vec());
+/// let mut screen = Vec::new();
+/// assert_eq!(read_masked_from(&mut keys, &mut screen, '•').unwrap(), "é€");
+/// assert_eq!(String::from_utf8(screen).unwrap(), "••\n");
+/// ```
+pub fn read_masked_from<R: Read, W: Write>(
+    reader: &mut R,
+    writer: &mut W,
+    mask: char,
+) -> Result<String, InputError<Infallible>> {
+    const BACKSPACE: u8 = 0x08;
+    const DELETE: u8 = 0x7F;
+    const CTRL_D: u8 = 0x04;
+
+    let mut secret = Vec::new();
+    let mut byte = [0];
+    loop {
+        if reader.read(&mut byte)? == 0 {
+            if secret.is_empty() {
+                return Err(InputError::Eof);
+            }
+            break;
+        }
+        match byte[0] {
+            b'\r' | b'\n' => break,
+            CTRL_D if secret.is_empty() => return Err(InputError::Eof),
+            CTRL_D => break,
+            BACKSPACE | DELETE => {
+                // Drop the continuation bytes of a multi-byte character, then its first byte.
+                while secret.pop_if(|b| *b & 0b1100_0000 == 0b1000_0000).is_some() {}
+                if secret.pop().is_some() {
+                    write!(writer, "\x08 \x08")?;
+                }
+            }
+            b => {
+                secret.push(b);
+                // Only the first byte of each character gets a mask.
+                if b & 0b1100_0000 != 0b1000_0000 {
+                    write!(writer, "{}", mask)?;
+                }
+            }
+        }
+        writer.flush()?;
+    }
+
+    writeln!(writer)?;
+    writer.flush()?;
+    String::from_utf8(secret).map_err(|e| InputError::Io(io::Error::new(io::ErrorKind::InvalidData, e)))
+}
+
+/// Compares `a` and `b` without stopping at the first differing byte.
+/// Only the lengths are allowed to leak.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let diff = a.iter().zip(b).fold(0, |diff, (x, y)| diff | (x ^ y));
+    std::hint::black_box(diff) == 0
+}
+
+/// Turns terminal echo, and line buffering for raw reads, back on when dropped.
+struct EchoGuard {
+    #[cfg(unix)]
+    original: libc::termios,
+    #[cfg(windows)]
+    handle: windows_sys::Win32::Foundation::HANDLE,
+    #[cfg(windows)]
+    original: windows_sys::Win32::System::Console::CONSOLE_MODE,
+}
+
+impl EchoGuard {
+    /// Turns off echo on the terminal attached to stdin, and line buffering too if `raw`.
+    /// Returns `Ok(None)` if stdin is not a terminal, leaving everything untouched.
+    #[cfg(unix)]
+    fn disable(raw: bool) -> io::Result<Option<Self>> {
+        if !io::stdin().is_terminal() {
+            return Ok(None);
+        }
+
+        let mut original = std::mem::MaybeUninit::<libc::termios>::uninit();
+        // SAFETY: `tcgetattr` fully initializes `original` when it returns 0.
+        if unsafe { libc::tcgetattr(libc::STDIN_FILENO, original.as_mut_ptr()) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        let original = unsafe { original.assume_init() };
+
+        let mut silent = original;
+        silent.c_lflag &= !libc::ECHO;
+        if raw {
+            // Deliver every keypress as soon as it's typed.
+            silent.c_lflag &= !libc::ICANON;
+            silent.c_cc[libc::VMIN] = 1;
+            silent.c_cc[libc::VTIME] = 0;
+        }
+        // SAFETY: `silent` is a valid termios obtained from `tcgetattr`.
+        if unsafe { libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &silent) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        Ok(Some(EchoGuard { original }))
+    }
+
+    /// Turns off echo on the console attached to stdin, and line input too if `raw`.
+    /// Returns `Ok(None)` if stdin is not a console, leaving everything untouched.
+    #[cfg(windows)]
+    fn disable(raw: bool) -> io::Result<Option<Self>> {
+        use windows_sys::Win32::System::Console::{
+            ENABLE_ECHO_INPUT, ENABLE_LINE_INPUT, GetConsoleMode, GetStdHandle, STD_INPUT_HANDLE, SetConsoleMode,
+        };
+
+        if !io::stdin().is_terminal() {
+            return Ok(None);
+        }
+
+        // SAFETY: plain Win32 calls on the process's own stdin handle.
+        unsafe {
+            let handle = GetStdHandle(STD_INPUT_HANDLE);
+            let mut original = 0;
+            if GetConsoleMode(handle, &mut original) == 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let mut silent = original & !ENABLE_ECHO_INPUT;
+            if raw {
+                silent &= !ENABLE_LINE_INPUT;
+            }
+            if SetConsoleMode(handle, silent) == 0 {
+                return Err(io::Error::last_os_error());
+            }
+            Ok(Some(EchoGuard { handle, original }))
+        }
+    }
+
+    /// Echo can't be controlled on this platform, so the secret is read as a plain line.
+    #[cfg(not(any(unix, windows)))]
+    fn disable(_raw: bool) -> io::Result<Option<Self>> {
+        Ok(None)
+    }
+}
+
+impl Drop for EchoGuard {
+    fn drop(&mut self) {
+        // SAFETY: restores the settings captured in `disable`.
+        #[cfg(unix)]
+        unsafe {
+            libc::tcsetattr(libc::STDIN_FILENO, libc::TCSANOW, &self.original);
+        }
+        #[cfg(windows)]
+        unsafe {
+            windows_sys::Win32::System::Console::SetConsoleMode(self.handle, self.original);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn generic_readers_only_write_the_prompt() {
+        // Whether or not the test runs in a terminal, a Cursor is not the terminal.
+        let mut output = Vec::new();
+        let secret = read_secret_from(&mut Cursor::new("s3cret\n"), &mut output, Some(format_args!("Token: "))).unwrap();
+        assert_eq!(secret, "s3cret\n");
+        assert_eq!(output, b"Token: ");
+    }
+
+    #[test]
+    fn confirmation_compares_whole_secrets() {
+        assert!(constant_time_eq(b"hunter2", b"hunter2"));
+        assert!(!constant_time_eq(b"hunter2", b"hunter3"));
+        assert!(!constant_time_eq(b"hunter2", b"hunter22"));
+
+        let mut reader = Cursor::new("same\nsame \n");
+        let result = read_password_confirmed_from(&mut reader, None, None);
+        assert_eq!(result.unwrap_err(), InputError::Mismatch);
+    }
+}
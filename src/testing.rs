@@ -0,0 +1,176 @@
+//! Test doubles for exercising input-reading code without touching real I/O.
+//!
+//! [`MockReader`] feeds pre-scripted lines, and [`MockWriter`] captures
+//! everything printed as a prompt, so a `#[test]` can drive the full
+//! [`InputReader`](crate::InputReader) path. [`MockSource`] is a line queue
+//! for functions that take an [`InputSource`].
+//!
+//! # Usage:
+//! ```
+//! use input_lib::{InputError, InputReader, PrintStyle};
+//! use input_lib::testing::{MockReader, MockWriter};
+//!
+//! let writer = MockWriter::new();
+//! let mut input = InputReader::new(MockReader::new(vec!["Alice", "30"]), writer.clone());
+//!
+//! let name: String = input.read("Name: ", PrintStyle::Continue).unwrap();
+//! let age: u8 = input.read("Age: ", PrintStyle::Continue).unwrap();
+//! let next: Result<String, _> = input.read("More? ", PrintStyle::Continue);
+//!
+//! assert_eq!((name.as_str(), age), ("Alice", 30));
+//! assert!(matches!(next, Err(InputError::Eof)));
+//! assert_eq!(writer.output(), "Name: Age: More? ");
+//! ```
+
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    io::{self, BufRead, Cursor, Read, Write},
+    rc::Rc,
+};
+
+use crate::InputSource;
+
+/// A `BufRead` source that returns canned lines in order, then EOF.
+#[derive(Debug, Clone)]
+pub struct MockReader {
+    data: Cursor<Vec<u8>>,
+}
+
+impl MockReader {
+    /// Creates a reader that yields each of `lines` once, in order.
+    ///
+    /// A trailing `\n` is appended to every line that doesn't already end with one.
+    pub fn new<I, S>(lines: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut data = Vec::new();
+        for line in lines {
+            let line = line.as_ref();
+            data.extend_from_slice(line.as_bytes());
+            if !line.ends_with('\n') {
+                data.push(b'\n');
+            }
+        }
+        MockReader { data: Cursor::new(data) }
+    }
+}
+
+impl Read for MockReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        self.data.read(buf)
+    }
+}
+
+impl BufRead for MockReader {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        self.data.fill_buf()
+    }
+
+    fn consume(&mut self, amt: usize) {
+        self.data.consume(amt)
+    }
+}
+
+/// An [`InputSource`] that hands out queued lines in order, then EOF.
+///
+/// More lines can be queued while a session is running, for example
+/// between two reads.
+///
+/// # Usage:
+/// ```
+/// use input_lib::{read_input_from, InputError, PrintStyle, TrimStyle};
+/// use input_lib::testing::MockSource;
+///
+/// let mut source = MockSource::new(["Alice", "30"]);
+///
+/// let name: String = read_input_from(&mut source, None, PrintStyle::Continue, TrimStyle::Trim).unwrap();
+/// let age: u8 = read_input_from(&mut source, None, PrintStyle::Continue, TrimStyle::Trim).unwrap();
+/// let next: Result<String, _> = read_input_from(&mut source, None, PrintStyle::Continue, TrimStyle::Trim);
+///
+/// assert_eq!((name.as_str(), age), ("Alice", 30));
+/// assert!(matches!(next, Err(InputError::Eof)));
+///
+/// source.push_line("yes");
+/// let answer: String = read_input_from(&mut source, None, PrintStyle::Continue, TrimStyle::Trim).unwrap();
+/// assert_eq!(answer, "yes");
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct MockSource {
+    lines: VecDeque<String>,
+}
+
+impl MockSource {
+    /// Creates a source that yields each of `lines` once, in order.
+    ///
+    /// A trailing `\n` is appended to every line that doesn't already end with one.
+    pub fn new<I, S>(lines: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let mut source = MockSource::default();
+        for line in lines {
+            source.push_line(line);
+        }
+        source
+    }
+
+    /// Queues one more line after the ones not yet read.
+    pub fn push_line(&mut self, line: impl AsRef<str>) {
+        let mut line = line.as_ref().to_owned();
+        if !line.ends_with('\n') {
+            line.push('\n');
+        }
+        self.lines.push_back(line);
+    }
+}
+
+impl InputSource for MockSource {
+    fn read_line(&mut self, buf: &mut String) -> io::Result<usize> {
+        let Some(line) = self.lines.pop_front() else {
+            return Ok(0);
+        };
+        buf.push_str(&line);
+        Ok(line.len())
+    }
+}
+
+/// A `Write` target that collects every written byte.
+///
+/// Clones share the same buffer, so a clone can be handed to an
+/// [`InputReader`](crate::InputReader) while the original is kept for assertions.
+#[derive(Debug, Clone, Default)]
+pub struct MockWriter {
+    buffer: Rc<RefCell<Vec<u8>>>,
+}
+
+impl MockWriter {
+    /// Creates an empty writer.
+    pub fn new() -> Self {
+        MockWriter::default()
+    }
+
+    /// Returns a copy of the bytes written so far.
+    pub fn bytes(&self) -> Vec<u8> {
+        self.buffer.borrow().clone()
+    }
+
+    /// Returns the text written so far, replacing invalid UTF-8 with `U+FFFD`.
+    pub fn output(&self) -> String {
+        String::from_utf8_lossy(&self.buffer.borrow()).into_owned()
+    }
+}
+
+impl Write for MockWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.buffer.borrow_mut().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}